@@ -1,8 +1,8 @@
-use frame_benchmarking::frame_support::PalletId;
+use frame_benchmarking::frame_support::{traits::Get, PalletId};
 use grandpa_primitives::AuthorityId as GrandpaId;
 use hex_literal::hex;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
-use polkadex_primitives::Block;
+use polkadex_primitives::{assets::AssetId, Block};
 pub use polkadex_primitives::{AccountId, Balance, Signature};
 use sc_chain_spec::ChainSpecExtension;
 use sc_service::ChainType;
@@ -18,16 +18,34 @@ use sp_runtime::{
 
 pub use node_polkadex_runtime::GenesisConfig;
 use node_polkadex_runtime::{
-	constants::currency::*, wasm_binary_unwrap, AuthorityDiscoveryConfig, BabeConfig,
-	BalancesConfig, CouncilConfig, ElectionsConfig, GrandpaConfig, ImOnlineConfig, IndicesConfig,
-	OrmlVestingConfig, PolkadexTreasuryModuleId, SessionConfig, SessionKeys, StakerStatus,
-	StakingConfig, SudoConfig, SystemConfig, TechnicalCommitteeConfig, MAX_NOMINATIONS,
+	constants::currency::*, wasm_binary_unwrap, AssetVestingConfig, AuthorityDiscoveryConfig,
+	BabeConfig, BalancesConfig, CouncilConfig, DemocracyConfig, ElectionsConfig, GrandpaConfig,
+	ImOnlineConfig, IndicesConfig, MaxRelayers, OrmlVestingConfig, PDEXMigrationConfig,
+	SessionConfig, SessionKeys, StakerStatus, StakingConfig, SystemConfig,
+	TechnicalCommitteeConfig, TechnicalMembershipConfig, TreasuryPalletId, MAX_NOMINATIONS,
 };
 
 type AccountPublic = <Signature as Verify>::Signer;
 
 const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
 
+/// Ethereum addresses the genesis validators relay deposits as, in the order of the initial
+/// authorities. These are the well-known development accounts of hardhat and ganache, whose keys
+/// are public, so governance has to replace them with `remove_relayer`/`add_relayer` on any
+/// network bridging real funds.
+const DEV_RELAYER_ADDRESSES: [[u8; 20]; 10] = [
+	hex!["f39Fd6e51aad88F6F4ce6aB8827279cffFb92266"],
+	hex!["70997970C51812dc3A010C7d01b50e0d17dc79C8"],
+	hex!["3C44CdDdB6a900fa2b585dd299e03d12FA4293BC"],
+	hex!["90F79bf6EB2c4f870365E785982E1f101E93b906"],
+	hex!["15d34AAf54267DB7D7c367839AAf71A00a2C6A65"],
+	hex!["9965507D1a55bcC2695C58ba16FB37d819B0A4dc"],
+	hex!["976EA74026E726554dB657fA54763abd0C3a0aa9"],
+	hex!["14dC79964da2C08b23698B3D3cc7Ca32193d9955"],
+	hex!["23618e81E3f5cdF7f54C3d65f7FBc0aBf5B21E8f"],
+	hex!["a0Ee7A142d267C1f36714E4a8F75612F20a79720"],
+];
+
 /// Node `ChainSpec` extensions.
 ///
 /// Additional parameters for some Substrate core modules,
@@ -109,15 +127,15 @@ fn udon_testnet_config_genesis() -> GenesisConfig {
 	];
 
 	// generated with secret: subkey inspect "$secret"/fir
-	let root_key: AccountId = hex![
+	let endowed_account: AccountId = hex![
 		// 5Ggr5JRSxCSZvwTc9Xkjca5bWkkmG1btufW22uLm5tArfV9y
 		"cc816e946438b2b21b8a3073f983ce03ee0feb313ec494e2dec462cfb4e77502"
 	]
 	.into();
 	// this is the accouint
-	let endowed_accounts: Vec<AccountId> = vec![root_key.clone()];
+	let endowed_accounts: Vec<AccountId> = vec![endowed_account];
 
-	testnet_genesis(initial_authorities, vec![], root_key, Some(endowed_accounts), false)
+	testnet_genesis(initial_authorities, vec![], Some(endowed_accounts), false)
 }
 
 /// Staging testnet config.
@@ -182,12 +200,11 @@ pub fn testnet_genesis(
 		AuthorityDiscoveryId,
 	)>,
 	initial_nominators: Vec<AccountId>,
-	root_key: AccountId,
 	endowed_accounts: Option<Vec<AccountId>>,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	let _genesis: AccountId = OCEXGenesisAccount.into_account();
-	let treasury_accont: AccountId = PolkadexTreasuryModuleId::get().into_account();
+	let treasury_accont: AccountId = TreasuryPalletId::get().into_account();
 	let mut investor_balances = vec![
 		(
 			hex!["e4cdc8abc0405db44c1a6886a2f2c59012fa3b98c07b61d63cc7f9e437ba243e"].into(),
@@ -671,6 +688,9 @@ pub fn testnet_genesis(
 			90000000000000000,
 		),
 	];
+	// Vesting of `AssetId::Asset` tokens as (account, asset, start, period, period count, amount
+	// per period). The vested amounts must be endowed in the `tokens` genesis.
+	let investor_asset_vesting: Vec<(AccountId, AssetId, u32, u32, u32, Balance)> = vec![];
 
 	let mut endowed_accounts: Vec<AccountId> = endowed_accounts.unwrap_or_else(|| {
 		vec![
@@ -719,6 +739,9 @@ pub fn testnet_genesis(
 		.collect::<Vec<_>>();
 
 	let num_endowed_accounts = endowed_accounts.len();
+	// Without sudo, governance needs a council and a technical committee from the first block.
+	let governance_members: Vec<AccountId> =
+		endowed_accounts.iter().take((num_endowed_accounts + 1) / 2).cloned().collect();
 
 	const ENDOWMENT: Balance = 10_000_000 * PDEX;
 	const STASH: Balance = ENDOWMENT / 1000;
@@ -727,6 +750,15 @@ pub fn testnet_genesis(
 
 	balances_vec.push((treasury_accont, 100000000 * PDEX));
 	balances_vec.append(&mut investor_balances);
+
+	// Validator controllers relay ERC20 PDEX deposits, a simple majority has to attest a mint.
+	let relayers: Vec<_> = initial_authorities
+		.iter()
+		.zip(DEV_RELAYER_ADDRESSES.iter())
+		.take(MaxRelayers::get() as usize)
+		.map(|(x, address)| (x.1.clone(), address.into()))
+		.collect();
+	let relayer_threshold = relayers.len() as u32 / 2 + 1;
 	GenesisConfig {
 		system: SystemConfig {
 			code: wasm_binary_unwrap().to_vec(),
@@ -755,24 +787,14 @@ pub fn testnet_genesis(
 			stakers,
 			..Default::default()
 		},
+		// `Elections` seeds the council members through `InitializeMembers`, so that they are
+		// re-elected with their stake at the end of the first term.
 		elections: ElectionsConfig {
-			members: endowed_accounts
-				.iter()
-				.take((num_endowed_accounts + 1) / 2)
-				.cloned()
-				.map(|member| (member, STASH))
-				.collect(),
+			members: governance_members.iter().cloned().map(|member| (member, STASH)).collect(),
 		},
 		council: CouncilConfig::default(),
-		technical_committee: TechnicalCommitteeConfig {
-			members: endowed_accounts
-				.iter()
-				.take((num_endowed_accounts + 1) / 2)
-				.cloned()
-				.collect(),
-			phantom: Default::default(),
-		},
-		sudo: SudoConfig { key: root_key },
+		democracy: DemocracyConfig::default(),
+		technical_committee: TechnicalCommitteeConfig::default(),
 		babe: BabeConfig {
 			authorities: vec![],
 			epoch_config: Some(node_polkadex_runtime::BABE_GENESIS_EPOCH_CONFIG),
@@ -780,22 +802,33 @@ pub fn testnet_genesis(
 		im_online: ImOnlineConfig { keys: vec![] },
 		authority_discovery: AuthorityDiscoveryConfig { keys: vec![] },
 		grandpa: GrandpaConfig { authorities: vec![] },
-		technical_membership: Default::default(),
+		// `TechnicalMembership` seeds the technical committee through `MembershipInitialized`, so
+		// that both agree on the members.
+		technical_membership: TechnicalMembershipConfig {
+			members: governance_members,
+			phantom: Default::default(),
+		},
 		treasury: Default::default(),
-		vesting: Default::default(),
 		orml_vesting: OrmlVestingConfig { vesting: investor_vesting },
 		tokens: Default::default(),
+		asset_registry: Default::default(),
+		asset_vesting: AssetVestingConfig { vesting: investor_asset_vesting },
+		pdex_migration: PDEXMigrationConfig {
+			address: hex!["3f0839385DB9cBEa8E73AdA6fa0CFe07E321F61d"].into(),
+			relayers,
+			threshold: relayer_threshold,
+			migration_caps: vec![(AssetId::POLKADEX, 20_000_000 * PDEX)],
+			tokens: vec![(
+				hex!["F59ae934f6fe444afC309586cC60a84a0F89Aaea"].into(),
+				AssetId::POLKADEX,
+				18,
+			)],
+		},
 	}
 }
 
 fn development_config_genesis() -> GenesisConfig {
-	testnet_genesis(
-		vec![authority_keys_from_seed("Alice")],
-		vec![],
-		get_account_id_from_seed::<sr25519::Public>("Alice"),
-		None,
-		true,
-	)
+	testnet_genesis(vec![authority_keys_from_seed("Alice")], vec![], None, true)
 }
 
 /// Development config (single validator Alice)
@@ -821,7 +854,6 @@ fn soba_testnet_genesis() -> GenesisConfig {
 			authority_keys_from_seed("Bob"),
 		],
 		vec![],
-		get_account_id_from_seed::<sr25519::Public>("Alice"),
 		None,
 		false,
 	)
@@ -848,13 +880,7 @@ pub(crate) mod tests {
 	use sp_runtime::BuildStorage;
 
 	fn local_testnet_genesis_instant_single() -> GenesisConfig {
-		testnet_genesis(
-			vec![authority_keys_from_seed("Alice")],
-			vec![],
-			get_account_id_from_seed::<sr25519::Public>("Alice"),
-			None,
-			false,
-		)
+		testnet_genesis(vec![authority_keys_from_seed("Alice")], vec![], None, false)
 	}
 
 	/// Local testnet config (single validator - Alice)
@@ -901,4 +927,16 @@ pub(crate) mod tests {
 	fn test_staging_test_net_chain_spec() {
 		assert!(!udon_testnet_config().build_storage().is_err());
 	}
+
+	#[test]
+	fn test_genesis_seeds_governance() {
+		for genesis in vec![
+			development_config_genesis(),
+			soba_testnet_genesis(),
+			udon_testnet_config_genesis(),
+		] {
+			assert!(!genesis.elections.members.is_empty());
+			assert!(!genesis.technical_membership.members.is_empty());
+		}
+	}
 }
@@ -0,0 +1,48 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for asset-fee-payment-pallet
+
+use super::*;
+use crate::Pallet as AssetFeePayment;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::traits::EnsureOrigin;
+
+benchmarks! {
+	set_conversion_rate {
+		let origin = T::RateOrigin::successful_origin();
+		let rate = FixedU128::saturating_from_rational(3, 2);
+	}: _<T::Origin>(origin, AssetId::Asset(1), Some(rate))
+	verify {
+		assert_eq!(AssetFeePayment::<T>::conversion_rate(AssetId::Asset(1)), Some(rate));
+	}
+
+	set_fee_distribution {
+		let origin = T::FeeSplitOrigin::successful_origin();
+		let split = FeeSplit {
+			treasury: Percent::from_percent(50),
+			author: Percent::from_percent(30),
+			burn: Percent::from_percent(20),
+		};
+	}: _<T::Origin>(origin, split, split)
+	verify {
+		assert_eq!(AssetFeePayment::<T>::fee_distribution(), split);
+	}
+}
+
+impl_benchmark_test_suite!(AssetFeePayment, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,240 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+//! # Asset Fee Payment Pallet
+//!
+//! Lets senders pay transaction fees in orml assets. The `ChargeAssetTxPayment` signed extension
+//! converts the PDEX-denominated fee computed by `pallet_transaction_payment` into the asset
+//! chosen by the sender at the rate stored in `ConversionRates`. Fees paid in native PDEX are
+//! handled by `ChargeTransactionPayment` as before.
+//!
+//! The pallet also stores how fees and tips are distributed between the treasury, the block
+//! author and burning, for the runtime's fee handlers to read.
+
+pub use pallet::*;
+pub use payment::{ChargeAssetTxPayment, InitialPayment, UNSUPPORTED_FEE_ASSET};
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode};
+use pallet_transaction_payment::OnChargeTransaction;
+use polkadex_primitives::assets::AssetId;
+use sp_runtime::{
+	traits::AtLeast32BitUnsigned, FixedPointNumber, FixedPointOperand, FixedU128, Percent,
+	RuntimeDebug,
+};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+mod payment;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+/// Balance type of the fees computed by `pallet_transaction_payment`.
+pub type BalanceOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+/// Handler for transaction fees paid in orml assets.
+pub trait OnAssetFees<Balance> {
+	/// Called after dispatch with the `fee` and `tip` the sender paid in `asset_id`. Amounts
+	/// that are not credited to any account are burnt.
+	fn on_asset_fees(asset_id: AssetId, fee: Balance, tip: Balance);
+}
+
+impl<Balance> OnAssetFees<Balance> for () {
+	fn on_asset_fees(_asset_id: AssetId, _fee: Balance, _tip: Balance) {}
+}
+
+/// Shares of fees or tips going to the treasury, the block author and burning.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct FeeSplit {
+	/// Share credited to the treasury
+	pub treasury: Percent,
+	/// Share credited to the block author
+	pub author: Percent,
+	/// Share burnt
+	pub burn: Percent,
+}
+
+impl Default for FeeSplit {
+	/// 80% to the treasury and 20% to the block author.
+	fn default() -> Self {
+		Self {
+			treasury: Percent::from_percent(80),
+			author: Percent::from_percent(20),
+			burn: Percent::from_percent(0),
+		}
+	}
+}
+
+impl FeeSplit {
+	/// Returns true if the shares add up to 100%.
+	pub fn is_valid(&self) -> bool {
+		self.treasury.deconstruct() as u16 +
+			self.author.deconstruct() as u16 +
+			self.burn.deconstruct() as u16 ==
+			100
+	}
+
+	/// Splits `amount` into the treasury, author and burn shares. Treasury and burn shares are
+	/// rounded down and the author receives the remainder.
+	pub fn split<Balance: AtLeast32BitUnsigned + Copy>(
+		&self,
+		amount: Balance,
+	) -> (Balance, Balance, Balance) {
+		let share = |percent: Percent| {
+			amount.saturating_mul(Balance::from(percent.deconstruct())) / Balance::from(100u8)
+		};
+		let treasury = share(self.treasury);
+		let burn = share(self.burn);
+		(treasury, amount.saturating_sub(treasury).saturating_sub(burn), burn)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use orml_traits::MultiCurrency;
+	use sp_runtime::traits::Zero;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Module that holds the assets fees are paid in
+		type Currency: MultiCurrency<
+			Self::AccountId,
+			CurrencyId = AssetId,
+			Balance = BalanceOf<Self>,
+		>;
+		/// Origin that sets conversion rates, governance or a price oracle
+		type RateOrigin: EnsureOrigin<Self::Origin>;
+		/// Receives the fees paid in orml assets
+		type OnAssetFees: OnAssetFees<BalanceOf<Self>>;
+		/// Origin that sets the distribution of fees and tips
+		type FeeSplitOrigin: EnsureOrigin<Self::Origin>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Amount of an asset charged for each unit of native PDEX fees
+	#[pallet::storage]
+	#[pallet::getter(fn conversion_rate)]
+	pub type ConversionRates<T> = StorageMap<_, Blake2_128Concat, AssetId, FixedU128, OptionQuery>;
+
+	/// Distribution of transaction fees
+	#[pallet::storage]
+	#[pallet::getter(fn fee_distribution)]
+	pub type FeeDistribution<T> = StorageValue<_, FeeSplit, ValueQuery>;
+
+	/// Distribution of transaction tips
+	#[pallet::storage]
+	#[pallet::getter(fn tip_distribution)]
+	pub type TipDistribution<T> = StorageValue<_, FeeSplit, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance")]
+	pub enum Event<T: Config> {
+		/// Fees may be paid in an asset at the given rate. \[asset_id, rate\]
+		ConversionRateSet(AssetId, FixedU128),
+		/// Fees may no longer be paid in an asset. \[asset_id\]
+		ConversionRateRemoved(AssetId),
+		/// A transaction fee was paid in an asset. \[who, asset_id, actual_fee, tip\]
+		AssetFeePaid(T::AccountId, AssetId, BalanceOf<T>, BalanceOf<T>),
+		/// The distribution of fees and tips changed. \[fees, tips\]
+		FeeDistributionChanged(FeeSplit, FeeSplit),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Fees in native PDEX are not converted.
+		NativeAsset,
+		/// The conversion rate must not be zero.
+		ZeroRate,
+		/// The shares of a split do not add up to 100%.
+		InvalidSplit,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the amount of `asset_id` charged per unit of native fees, or stop accepting fees
+		/// in `asset_id` if `rate` is `None`.
+		#[pallet::weight(T::WeightInfo::set_conversion_rate())]
+		pub fn set_conversion_rate(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			rate: Option<FixedU128>,
+		) -> DispatchResult {
+			T::RateOrigin::ensure_origin(origin)?;
+			ensure!(asset_id != AssetId::POLKADEX, Error::<T>::NativeAsset);
+			match rate {
+				Some(rate) => {
+					ensure!(!rate.is_zero(), Error::<T>::ZeroRate);
+					ConversionRates::<T>::insert(asset_id, rate);
+					Self::deposit_event(Event::ConversionRateSet(asset_id, rate));
+				},
+				None => {
+					ConversionRates::<T>::remove(asset_id);
+					Self::deposit_event(Event::ConversionRateRemoved(asset_id));
+				},
+			}
+			Ok(())
+		}
+
+		/// Set how transaction fees and tips are distributed between the treasury, the block
+		/// author and burning.
+		#[pallet::weight(T::WeightInfo::set_fee_distribution())]
+		pub fn set_fee_distribution(
+			origin: OriginFor<T>,
+			fees: FeeSplit,
+			tips: FeeSplit,
+		) -> DispatchResult {
+			T::FeeSplitOrigin::ensure_origin(origin)?;
+			ensure!(fees.is_valid() && tips.is_valid(), Error::<T>::InvalidSplit);
+			FeeDistribution::<T>::put(fees);
+			TipDistribution::<T>::put(tips);
+			Self::deposit_event(Event::FeeDistributionChanged(fees, tips));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T>
+where
+	BalanceOf<T>: FixedPointOperand,
+{
+	/// Converts a native fee into the asset amount charged at `rate`.
+	pub fn to_asset_balance(rate: FixedU128, fee: BalanceOf<T>) -> BalanceOf<T> {
+		rate.saturating_mul_int(fee)
+	}
+}
@@ -0,0 +1,246 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Signed extension charging transaction fees in native PDEX or in an orml asset.
+
+use crate::{BalanceOf, Config, Event, OnAssetFees, Pallet};
+use codec::{Decode, Encode};
+use frame_support::{
+	traits::Get,
+	weights::{DispatchClass, DispatchInfo, PostDispatchInfo},
+};
+use orml_traits::MultiCurrency;
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use polkadex_primitives::assets::AssetId;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, Saturating, SignedExtension, Zero},
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError,
+		ValidTransaction,
+	},
+	DispatchResult, FixedPointOperand, FixedU128, SaturatedConversion,
+};
+
+/// `InvalidTransaction::Custom` code of fees in an asset without a conversion rate.
+pub const UNSUPPORTED_FEE_ASSET: u8 = 1;
+
+/// Require the transactor to pay for themselves and maybe include a tip to gain additional
+/// priority in the queue.
+///
+/// Fees and tip are paid in native PDEX unless `asset_id` names another asset, in which case
+/// they are converted at the asset's conversion rate.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct ChargeAssetTxPayment<T: Config> {
+	#[codec(compact)]
+	tip: BalanceOf<T>,
+	asset_id: Option<AssetId>,
+}
+
+/// Fee withdrawn before dispatch, settled after dispatch.
+pub enum InitialPayment<T: Config> {
+	/// No fee was withdrawn, as for unsigned transactions
+	Nothing,
+	/// Fee withdrawn by `ChargeTransactionPayment`
+	Native(
+		(
+			BalanceOf<T>,
+			T::AccountId,
+			<T::OnChargeTransaction as OnChargeTransaction<T>>::LiquidityInfo,
+		),
+	),
+	/// Fee withdrawn in an asset
+	Asset {
+		/// Account that paid the fee
+		who: T::AccountId,
+		/// Asset the fee was paid in
+		asset_id: AssetId,
+		/// Conversion rate the fee was computed at
+		rate: FixedU128,
+		/// Asset amount withdrawn, including the tip
+		paid: BalanceOf<T>,
+		/// Native tip
+		tip: BalanceOf<T>,
+	},
+}
+
+impl<T: Config> Default for InitialPayment<T> {
+	fn default() -> Self {
+		Self::Nothing
+	}
+}
+
+impl<T: Config> ChargeAssetTxPayment<T>
+where
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	/// Utility constructor. Used only in client/factory code.
+	pub fn from(tip: BalanceOf<T>, asset_id: Option<AssetId>) -> Self {
+		Self { tip, asset_id }
+	}
+
+	/// Asset the fee is paid in, `None` for native PDEX.
+	fn fee_asset(&self) -> Option<AssetId> {
+		self.asset_id.filter(|asset_id| *asset_id != AssetId::POLKADEX)
+	}
+
+	fn native(&self) -> ChargeTransactionPayment<T> {
+		ChargeTransactionPayment::from(self.tip)
+	}
+
+	/// Withdraws the fee in `asset_id` and returns the conversion rate, the asset amount
+	/// withdrawn and the native fee.
+	fn withdraw_asset_fee(
+		&self,
+		who: &T::AccountId,
+		asset_id: AssetId,
+		info: &DispatchInfoOf<T::Call>,
+		len: usize,
+	) -> Result<(FixedU128, BalanceOf<T>, BalanceOf<T>), TransactionValidityError> {
+		let rate = Pallet::<T>::conversion_rate(asset_id)
+			.ok_or(InvalidTransaction::Custom(UNSUPPORTED_FEE_ASSET))?;
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+		let asset_fee = Pallet::<T>::to_asset_balance(rate, fee);
+		if asset_fee.is_zero() {
+			// A fee rounded down to nothing would make the transaction free.
+			if !fee.is_zero() {
+				return Err(InvalidTransaction::Payment.into())
+			}
+			return Ok((rate, asset_fee, fee))
+		}
+		T::Currency::withdraw(asset_id, who, asset_fee).map_err(|_| InvalidTransaction::Payment)?;
+		Ok((rate, asset_fee, fee))
+	}
+
+	/// Priority of a transaction paying the native fee `final_fee`, computed like
+	/// `ChargeTransactionPayment` does for native fees, which keeps its own helper private.
+	fn get_priority(
+		len: usize,
+		info: &DispatchInfoOf<T::Call>,
+		final_fee: BalanceOf<T>,
+	) -> TransactionPriority {
+		let weight_saturation = T::BlockWeights::get().max_block / info.weight.max(1);
+		let max_block_length = *T::BlockLength::get().max.get(DispatchClass::Normal);
+		let len_saturation = max_block_length as u64 / (len as u64).max(1);
+		let coefficient: BalanceOf<T> =
+			weight_saturation.min(len_saturation).saturated_into::<BalanceOf<T>>();
+		final_fee.saturating_mul(coefficient).saturated_into::<TransactionPriority>()
+	}
+}
+
+impl<T: Config> sp_std::fmt::Debug for ChargeAssetTxPayment<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeAssetTxPayment<{:?}, {:?}>", self.tip, self.asset_id)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config> SignedExtension for ChargeAssetTxPayment<T>
+where
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "ChargeAssetTxPayment";
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = ();
+	type Pre = InitialPayment<T>;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		match self.fee_asset() {
+			None => self.native().validate(who, call, info, len),
+			Some(asset_id) => {
+				let (_, _, fee) = self.withdraw_asset_fee(who, asset_id, info, len)?;
+				Ok(ValidTransaction {
+					priority: Self::get_priority(len, info, fee),
+					..Default::default()
+				})
+			},
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		match self.fee_asset() {
+			None => self.native().pre_dispatch(who, call, info, len).map(InitialPayment::Native),
+			Some(asset_id) => {
+				let (rate, paid, _) = self.withdraw_asset_fee(who, asset_id, info, len)?;
+				Ok(InitialPayment::Asset { who: who.clone(), asset_id, rate, paid, tip: self.tip })
+			},
+		}
+	}
+
+	fn post_dispatch(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		match pre {
+			InitialPayment::Nothing => Ok(()),
+			InitialPayment::Native(pre) =>
+				ChargeTransactionPayment::<T>::post_dispatch(pre, info, post_info, len, result),
+			InitialPayment::Asset { who, asset_id, rate, paid, tip } => {
+				let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+					len as u32, info, post_info, tip,
+				);
+				let mut actual_fee = Pallet::<T>::to_asset_balance(rate, actual_fee).min(paid);
+				let refund = paid.saturating_sub(actual_fee);
+				if !refund.is_zero() {
+					// The transaction was already dispatched, so a refund that can not be
+					// credited is kept as part of the fee instead of failing the block.
+					if let Err(e) = T::Currency::deposit(asset_id, &who, refund) {
+						log::warn!(
+							target: "runtime::asset-fee-payment",
+							"failed to refund {:?} of asset {:?} to {:?}: {:?}",
+							refund,
+							asset_id,
+							who,
+							e,
+						);
+						actual_fee = paid;
+					}
+				}
+				let tip = Pallet::<T>::to_asset_balance(rate, tip).min(actual_fee);
+				T::OnAssetFees::on_asset_fees(asset_id, actual_fee.saturating_sub(tip), tip);
+				Pallet::<T>::deposit_event(Event::AssetFeePaid(who, asset_id, actual_fee, tip));
+				Ok(())
+			},
+		}
+	}
+}
@@ -0,0 +1,356 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, ChargeAssetTxPayment, Error, FeeSplit, UNSUPPORTED_FEE_ASSET};
+use frame_support::{
+	assert_noop, assert_ok,
+	weights::{DispatchInfo, PostDispatchInfo, Weight},
+};
+use orml_traits::MultiCurrency;
+use polkadex_primitives::assets::AssetId;
+use sp_runtime::{
+	traits::SignedExtension,
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	DispatchError, FixedPointNumber, FixedU128, Percent,
+};
+
+const CALL: &<Test as frame_system::Config>::Call =
+	&Call::Balances(pallet_balances::Call::transfer(BOB, 69));
+
+/// Length of the test extrinsics, adding 10 to the native fee.
+const LEN: usize = 10;
+
+fn info(weight: Weight) -> DispatchInfo {
+	DispatchInfo { weight, ..Default::default() }
+}
+
+fn post_info(actual_weight: Weight) -> PostDispatchInfo {
+	PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Default::default() }
+}
+
+fn asset_balance(asset_id: AssetId, who: AccountId) -> Balance {
+	<Tokens as MultiCurrency<AccountId>>::free_balance(asset_id, &who)
+}
+
+fn last_event() -> Event {
+	System::events().pop().expect("an event was deposited").event
+}
+
+/// Charges `who` for a call of weight 10 that used `actual_weight` and returns the result of
+/// the withdrawal.
+fn charge(
+	who: AccountId,
+	tip: Balance,
+	asset_id: Option<AssetId>,
+	actual_weight: Weight,
+) -> Result<(), TransactionValidityError> {
+	let pre = ChargeAssetTxPayment::<Test>::from(tip, asset_id).pre_dispatch(
+		&who,
+		CALL,
+		&info(10),
+		LEN,
+	)?;
+	ChargeAssetTxPayment::<Test>::post_dispatch(
+		pre,
+		&info(10),
+		&post_info(actual_weight),
+		LEN,
+		&Ok(()),
+	)
+}
+
+#[test]
+fn native_fees_are_charged_by_transaction_payment() {
+	new_test_ext().execute_with(|| {
+		// base 5 + length 10 + weight 10, with 5 of weight refunded
+		assert_ok!(charge(ALICE, 0, None, 5));
+		assert_eq!(Balances::free_balance(ALICE), 1_000 - 20);
+
+		assert_ok!(charge(ALICE, 0, Some(AssetId::POLKADEX), 10));
+		assert_eq!(Balances::free_balance(ALICE), 1_000 - 20 - 25);
+		assert_eq!(asset_balance(AssetId::Asset(1), ALICE), 1_000);
+	});
+}
+
+#[test]
+fn asset_fees_are_converted_and_refunded() {
+	new_test_ext().execute_with(|| {
+		let rate = FixedU128::saturating_from_integer(2);
+		assert_ok!(AssetFeePayment::set_conversion_rate(
+			Origin::root(),
+			AssetId::Asset(1),
+			Some(rate)
+		));
+
+		let pre = ChargeAssetTxPayment::<Test>::from(0, Some(AssetId::Asset(1)))
+			.pre_dispatch(&ALICE, CALL, &info(10), LEN)
+			.unwrap();
+		assert_eq!(asset_balance(AssetId::Asset(1), ALICE), 1_000 - 50);
+
+		assert_ok!(ChargeAssetTxPayment::<Test>::post_dispatch(
+			pre,
+			&info(10),
+			&post_info(5),
+			LEN,
+			&Ok(())
+		));
+		assert_eq!(asset_balance(AssetId::Asset(1), ALICE), 1_000 - 40);
+		assert_eq!(asset_balance(AssetId::Asset(1), TREASURY), 40);
+		assert_eq!(asset_balance(AssetId::Asset(1), AUTHOR), 0);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+		assert_eq!(
+			last_event(),
+			Event::AssetFeePayment(crate::Event::AssetFeePaid(ALICE, AssetId::Asset(1), 40, 0))
+		);
+	});
+}
+
+#[test]
+fn asset_tips_are_routed_separately() {
+	new_test_ext().execute_with(|| {
+		let rate = FixedU128::saturating_from_rational(3, 2);
+		assert_ok!(AssetFeePayment::set_conversion_rate(
+			Origin::root(),
+			AssetId::Asset(1),
+			Some(rate)
+		));
+
+		// fee 20 and tip 10 in PDEX
+		assert_ok!(charge(ALICE, 10, Some(AssetId::Asset(1)), 5));
+		assert_eq!(asset_balance(AssetId::Asset(1), ALICE), 1_000 - 45);
+		assert_eq!(asset_balance(AssetId::Asset(1), TREASURY), 30);
+		assert_eq!(asset_balance(AssetId::Asset(1), AUTHOR), 15);
+		assert_eq!(
+			last_event(),
+			Event::AssetFeePayment(crate::Event::AssetFeePaid(ALICE, AssetId::Asset(1), 45, 15))
+		);
+	});
+}
+
+#[test]
+fn asset_fees_require_rate_and_balance() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			charge(ALICE, 0, Some(AssetId::Asset(1)), 10),
+			Err(InvalidTransaction::Custom(UNSUPPORTED_FEE_ASSET).into())
+		);
+		assert_eq!(
+			ChargeAssetTxPayment::<Test>::from(0, Some(AssetId::Asset(1))).validate(
+				&ALICE,
+				CALL,
+				&info(10),
+				LEN
+			),
+			Err(InvalidTransaction::Custom(UNSUPPORTED_FEE_ASSET).into())
+		);
+
+		let rate = FixedU128::saturating_from_integer(2);
+		assert_ok!(AssetFeePayment::set_conversion_rate(
+			Origin::root(),
+			AssetId::Asset(2),
+			Some(rate)
+		));
+		assert_eq!(
+			charge(ALICE, 0, Some(AssetId::Asset(2)), 10),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert_ok!(charge(BOB, 0, Some(AssetId::Asset(2)), 10));
+		assert_eq!(asset_balance(AssetId::Asset(2), BOB), 1_000 - 50);
+	});
+}
+
+#[test]
+fn asset_fees_must_not_round_to_zero() {
+	new_test_ext().execute_with(|| {
+		let rate = FixedU128::saturating_from_rational(1, 1_000);
+		assert_ok!(AssetFeePayment::set_conversion_rate(
+			Origin::root(),
+			AssetId::Asset(1),
+			Some(rate)
+		));
+		assert_eq!(
+			charge(ALICE, 0, Some(AssetId::Asset(1)), 10),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert_eq!(
+			ChargeAssetTxPayment::<Test>::from(0, Some(AssetId::Asset(1))).validate(
+				&ALICE,
+				CALL,
+				&info(10),
+				LEN
+			),
+			Err(InvalidTransaction::Payment.into())
+		);
+	});
+}
+
+#[test]
+fn asset_fees_get_native_priority() {
+	new_test_ext().execute_with(|| {
+		let rate = FixedU128::saturating_from_integer(2);
+		assert_ok!(AssetFeePayment::set_conversion_rate(
+			Origin::root(),
+			AssetId::Asset(1),
+			Some(rate)
+		));
+		let priority = |asset_id| {
+			ChargeAssetTxPayment::<Test>::from(5, asset_id)
+				.validate(&ALICE, CALL, &info(10), LEN)
+				.unwrap()
+				.priority
+		};
+		assert!(priority(None) > 0);
+		assert_eq!(priority(Some(AssetId::Asset(1))), priority(None));
+	});
+}
+
+#[test]
+fn failed_asset_refunds_are_kept_as_fee() {
+	new_test_ext().execute_with(|| {
+		let rate = FixedU128::saturating_from_integer(2);
+		assert_ok!(AssetFeePayment::set_conversion_rate(
+			Origin::root(),
+			AssetId::Asset(1),
+			Some(rate)
+		));
+		let pre = ChargeAssetTxPayment::<Test>::from(0, Some(AssetId::Asset(1)))
+			.pre_dispatch(&ALICE, CALL, &info(10), LEN)
+			.unwrap();
+		// Any further deposit of the asset overflows its total issuance.
+		let headroom = Balance::MAX - Tokens::total_issuance(AssetId::Asset(1));
+		assert_ok!(<Tokens as MultiCurrency<AccountId>>::deposit(
+			AssetId::Asset(1),
+			&BOB,
+			headroom
+		));
+
+		assert_ok!(ChargeAssetTxPayment::<Test>::post_dispatch(
+			pre,
+			&info(10),
+			&post_info(5),
+			LEN,
+			&Ok(())
+		));
+		assert_eq!(asset_balance(AssetId::Asset(1), ALICE), 1_000 - 50);
+		assert_eq!(
+			last_event(),
+			Event::AssetFeePayment(crate::Event::AssetFeePaid(ALICE, AssetId::Asset(1), 50, 0))
+		);
+	});
+}
+
+#[test]
+fn conversion_rates_are_set_by_rate_origin() {
+	new_test_ext().execute_with(|| {
+		let rate = FixedU128::saturating_from_integer(2);
+		assert_noop!(
+			AssetFeePayment::set_conversion_rate(
+				Origin::signed(ALICE),
+				AssetId::Asset(1),
+				Some(rate)
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetFeePayment::set_conversion_rate(Origin::root(), AssetId::POLKADEX, Some(rate)),
+			Error::<Test>::NativeAsset
+		);
+		assert_noop!(
+			AssetFeePayment::set_conversion_rate(
+				Origin::root(),
+				AssetId::Asset(1),
+				Some(FixedU128::from_inner(0))
+			),
+			Error::<Test>::ZeroRate
+		);
+
+		assert_ok!(AssetFeePayment::set_conversion_rate(
+			Origin::root(),
+			AssetId::Asset(1),
+			Some(rate)
+		));
+		assert_eq!(AssetFeePayment::conversion_rate(AssetId::Asset(1)), Some(rate));
+		assert_eq!(
+			last_event(),
+			Event::AssetFeePayment(crate::Event::ConversionRateSet(AssetId::Asset(1), rate))
+		);
+
+		assert_ok!(AssetFeePayment::set_conversion_rate(Origin::root(), AssetId::Asset(1), None));
+		assert_eq!(AssetFeePayment::conversion_rate(AssetId::Asset(1)), None);
+		assert_eq!(
+			last_event(),
+			Event::AssetFeePayment(crate::Event::ConversionRateRemoved(AssetId::Asset(1)))
+		);
+	});
+}
+
+#[test]
+fn fee_split_defaults_to_treasury_and_author() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(AssetFeePayment::fee_distribution(), FeeSplit::default());
+		assert_eq!(AssetFeePayment::tip_distribution(), FeeSplit::default());
+		assert!(FeeSplit::default().is_valid());
+		assert_eq!(FeeSplit::default().split(100u64), (80, 20, 0));
+		// the author receives what rounding leaves
+		assert_eq!(FeeSplit::default().split(9u64), (7, 2, 0));
+
+		let split = FeeSplit {
+			treasury: Percent::from_percent(33),
+			author: Percent::from_percent(33),
+			burn: Percent::from_percent(34),
+		};
+		assert_eq!(split.split(10u64), (3, 4, 3));
+	});
+}
+
+#[test]
+fn fee_distribution_is_set_by_fee_split_origin() {
+	new_test_ext().execute_with(|| {
+		let fees = FeeSplit {
+			treasury: Percent::from_percent(50),
+			author: Percent::from_percent(20),
+			burn: Percent::from_percent(30),
+		};
+		let tips = FeeSplit {
+			treasury: Percent::from_percent(0),
+			author: Percent::from_percent(100),
+			burn: Percent::from_percent(0),
+		};
+		assert_noop!(
+			AssetFeePayment::set_fee_distribution(Origin::signed(ALICE), fees, tips),
+			DispatchError::BadOrigin
+		);
+		let invalid = FeeSplit { burn: Percent::from_percent(40), ..fees };
+		assert_noop!(
+			AssetFeePayment::set_fee_distribution(Origin::root(), invalid, tips),
+			Error::<Test>::InvalidSplit
+		);
+		assert_noop!(
+			AssetFeePayment::set_fee_distribution(Origin::root(), fees, invalid),
+			Error::<Test>::InvalidSplit
+		);
+
+		assert_ok!(AssetFeePayment::set_fee_distribution(Origin::root(), fees, tips));
+		assert_eq!(AssetFeePayment::fee_distribution(), fees);
+		assert_eq!(AssetFeePayment::tip_distribution(), tips);
+		assert_eq!(
+			last_event(),
+			Event::AssetFeePayment(crate::Event::FeeDistributionChanged(fees, tips))
+		);
+	});
+}
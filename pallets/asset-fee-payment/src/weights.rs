@@ -0,0 +1,59 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for asset_fee_payment_pallet
+//!
+//! These weights are estimates and have not been produced by the benchmark CLI yet. Replace this
+//! file with the output of `./scripts/benchmark.sh asset_fee_payment_pallet` run on the reference
+//! hardware.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for asset_fee_payment_pallet.
+pub trait WeightInfo {
+	fn set_conversion_rate() -> Weight;
+	fn set_fee_distribution() -> Weight;
+}
+
+/// Weights for asset_fee_payment_pallet using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn set_conversion_rate() -> Weight {
+		(17_284_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_fee_distribution() -> Weight {
+		(16_032_000 as Weight).saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_conversion_rate() -> Weight {
+		(17_284_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_fee_distribution() -> Weight {
+		(16_032_000 as Weight).saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}
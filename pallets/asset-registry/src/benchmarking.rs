@@ -0,0 +1,133 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for asset-registry-pallet
+
+use super::*;
+use crate::Pallet as AssetRegistry;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Contains, EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, StaticLookup};
+use sp_std::vec;
+
+const ASSET: AssetId = AssetId::Asset(1);
+
+/// Returns name and symbol of the maximum length.
+fn metadata<T: Config>() -> (Vec<u8>, Vec<u8>) {
+	let limit = T::StringLimit::get() as usize;
+	(vec![b'n'; limit], vec![b's'; limit])
+}
+
+/// Registers `ASSET` with a funded whitelisted caller as issuer.
+fn register_asset<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	let (name, symbol) = metadata::<T>();
+	AssetRegistry::<T>::register(
+		RawOrigin::Signed(caller.clone()).into(),
+		ASSET,
+		name,
+		symbol,
+		12,
+		1u32.into(),
+	)
+	.expect("the caller can pay the deposit; qed");
+	caller
+}
+
+benchmarks! {
+	register {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let (name, symbol) = metadata::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), ASSET, name, symbol, 12, 1u32.into())
+	verify {
+		assert_eq!(AssetRegistry::<T>::assets(ASSET).map(|asset| asset.issuer), Some(caller));
+	}
+
+	force_register {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let issuer: T::AccountId = whitelisted_caller();
+		let (name, symbol) = metadata::<T>();
+	}: _<T::Origin>(origin, ASSET, T::Lookup::unlookup(issuer), name, symbol, 12, 1u32.into())
+	verify {
+		assert!(AssetRegistry::<T>::is_active(ASSET));
+	}
+
+	freeze {
+		let caller = register_asset::<T>();
+	}: _(RawOrigin::Signed(caller), ASSET)
+	verify {
+		assert!(!AssetRegistry::<T>::is_active(ASSET));
+	}
+
+	thaw {
+		let caller = register_asset::<T>();
+		AssetRegistry::<T>::freeze(RawOrigin::Signed(caller.clone()).into(), ASSET)?;
+	}: _(RawOrigin::Signed(caller), ASSET)
+	verify {
+		assert!(AssetRegistry::<T>::is_active(ASSET));
+	}
+
+	retire {
+		let caller = register_asset::<T>();
+	}: _(RawOrigin::Signed(caller), ASSET)
+	verify {
+		assert_eq!(
+			AssetRegistry::<T>::assets(ASSET).map(|asset| asset.status),
+			Some(AssetStatus::Retired)
+		);
+	}
+
+	set_issuer {
+		register_asset::<T>();
+		let origin = T::GovernanceOrigin::successful_origin();
+		let issuer: T::AccountId = account("issuer", 0, 0);
+	}: _<T::Origin>(origin, ASSET, T::Lookup::unlookup(issuer.clone()))
+	verify {
+		assert_eq!(AssetRegistry::<T>::assets(ASSET).map(|asset| asset.issuer), Some(issuer));
+	}
+
+	set_existential_deposit {
+		register_asset::<T>();
+		let origin = T::GovernanceOrigin::successful_origin();
+	}: _<T::Origin>(origin, ASSET, 10u32.into())
+	verify {
+		assert_eq!(AssetRegistry::<T>::existential_deposit(ASSET), Some(10u32.into()));
+	}
+
+	add_to_dust_whitelist {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let who: T::AccountId = whitelisted_caller();
+	}: _<T::Origin>(origin, T::Lookup::unlookup(who.clone()))
+	verify {
+		assert!(AssetRegistry::<T>::contains(&who));
+	}
+
+	remove_from_dust_whitelist {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let who: T::AccountId = whitelisted_caller();
+		DustRemovalWhitelist::<T>::insert(&who, ());
+	}: _<T::Origin>(origin, T::Lookup::unlookup(who.clone()))
+	verify {
+		assert!(!AssetRegistry::<T>::contains(&who));
+	}
+}
+
+impl_benchmark_test_suite!(AssetRegistry, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,665 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+//! # Asset Registry Pallet
+//!
+//! Records the name, symbol, decimals, issuer and existential deposit of every
+//! `AssetId::Asset` held in `orml_tokens`. Assets are registered by governance, or by any account
+//! reserving `AssetDeposit`, and move from `Active` to `Frozen` and back, or to `Retired` once no
+//! units are left. The runtime reads existential deposits and asset status from this pallet.
+//!
+//! The pallet also keeps a governance-managed set of accounts whose `orml_tokens` balances are
+//! never removed as dust.
+//!
+//! [`FreezableAssets`] wraps the `orml_tokens` currency so that balances of assets which are not
+//! active can not be moved by any pallet using it.
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode};
+use frame_support::{
+	ensure,
+	traits::{BalanceStatus, Contains, Currency, LockIdentifier, ReservableCurrency},
+};
+use orml_traits::{
+	MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency,
+};
+use polkadex_primitives::assets::AssetId;
+use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+/// Balance type of the native currency reserved for registrations.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Lifecycle of a registered asset.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub enum AssetStatus {
+	/// The asset may be transferred
+	Active,
+	/// Transfers of the asset are rejected until it is thawed
+	Frozen,
+	/// The asset has no units left and its id may not be reused
+	Retired,
+}
+
+/// Metadata of a registered asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct AssetMetadata<AccountId, Balance> {
+	/// Name of the asset
+	pub name: Vec<u8>,
+	/// Ticker symbol of the asset
+	pub symbol: Vec<u8>,
+	/// Number of decimals of the asset
+	pub decimals: u8,
+	/// Account that may freeze, thaw and retire the asset
+	pub issuer: AccountId,
+	/// Native balance reserved from the issuer, returned when the asset is retired
+	pub deposit: Balance,
+	/// Minimum balance of an account holding the asset
+	pub existential_deposit: Balance,
+	/// Current lifecycle status
+	pub status: AssetStatus,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{StaticLookup, Zero};
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Native currency the registration deposit is reserved in
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Module that holds the registered assets
+		type Assets: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = BalanceOf<Self>>;
+		/// Origin that registers assets without a deposit and manages any asset
+		type GovernanceOrigin: EnsureOrigin<Self::Origin>;
+		/// Deposit reserved from accounts registering an asset
+		#[pallet::constant]
+		type AssetDeposit: Get<BalanceOf<Self>>;
+		/// Maximum length of asset names and symbols
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+		/// Largest existential deposit an asset may require
+		#[pallet::constant]
+		type MaxExistentialDeposit: Get<BalanceOf<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Metadata of registered assets
+	#[pallet::storage]
+	#[pallet::getter(fn assets)]
+	pub type Assets<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		AssetMetadata<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Accounts exempt from dust removal
+	#[pallet::storage]
+	pub type DustRemovalWhitelist<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Assets registered without deposit as id, issuer, name, symbol, decimals and
+		/// existential deposit
+		pub assets: Vec<(AssetId, T::AccountId, Vec<u8>, Vec<u8>, u8, BalanceOf<T>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { assets: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (asset_id, issuer, name, symbol, decimals, existential_deposit) in &self.assets {
+				assert!(*asset_id != AssetId::POLKADEX, "Native asset registered in genesis");
+				assert!(!Assets::<T>::contains_key(asset_id), "Asset registered twice in genesis");
+				assert!(
+					name.len() as u32 <= T::StringLimit::get() &&
+						symbol.len() as u32 <= T::StringLimit::get(),
+					"Asset metadata too long in genesis"
+				);
+				assert!(
+					*existential_deposit <= T::MaxExistentialDeposit::get(),
+					"Asset existential deposit too high in genesis"
+				);
+				Assets::<T>::insert(
+					asset_id,
+					AssetMetadata {
+						name: name.clone(),
+						symbol: symbol.clone(),
+						decimals: *decimals,
+						issuer: issuer.clone(),
+						deposit: Zero::zero(),
+						existential_deposit: *existential_deposit,
+						status: AssetStatus::Active,
+					},
+				);
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance")]
+	pub enum Event<T: Config> {
+		/// An asset was registered. \[asset_id, issuer\]
+		Registered(AssetId, T::AccountId),
+		/// Transfers of an asset were frozen. \[asset_id\]
+		Frozen(AssetId),
+		/// Transfers of an asset were allowed again. \[asset_id\]
+		Thawed(AssetId),
+		/// An asset was retired. \[asset_id\]
+		Retired(AssetId),
+		/// Governance handed the issuer rights of an asset to another account.
+		/// \[asset_id, issuer\]
+		IssuerChanged(AssetId, T::AccountId),
+		/// The existential deposit of an asset changed. \[asset_id, existential_deposit\]
+		ExistentialDepositSet(AssetId, BalanceOf<T>),
+		/// An account was exempted from dust removal. \[who\]
+		DustWhitelistAdded(T::AccountId),
+		/// An account is no longer exempt from dust removal. \[who\]
+		DustWhitelistRemoved(T::AccountId),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Native PDEX is not registered.
+		NativeAsset,
+		/// The asset id is already registered or was retired.
+		AssetExists,
+		/// The asset is not registered.
+		UnknownAsset,
+		/// The name or symbol is longer than `StringLimit`.
+		BadMetadata,
+		/// The existential deposit is above `MaxExistentialDeposit`.
+		ExistentialDepositTooHigh,
+		/// Only the issuer or governance may manage the asset.
+		NoPermission,
+		/// The asset is not active.
+		AssetNotActive,
+		/// The asset is not frozen.
+		AssetNotFrozen,
+		/// The asset is retired.
+		AssetRetired,
+		/// Units of the asset are still issued.
+		AssetInUse,
+		/// The account is already exempt from dust removal.
+		AlreadyWhitelisted,
+		/// The account is not exempt from dust removal.
+		NotWhitelisted,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register `asset_id` with the sender as issuer, reserving `AssetDeposit`. Only ids
+		/// without issued units may be registered this way; assets already in circulation are
+		/// registered by governance through `force_register`.
+		#[pallet::weight(T::WeightInfo::register())]
+		pub fn register(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			#[pallet::compact] existential_deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+			Self::ensure_new_asset(asset_id, &name, &symbol, existential_deposit)?;
+			ensure!(T::Assets::total_issuance(asset_id).is_zero(), Error::<T>::AssetInUse);
+			let deposit = T::AssetDeposit::get();
+			T::Currency::reserve(&issuer, deposit)?;
+			Self::do_register(
+				asset_id,
+				issuer,
+				name,
+				symbol,
+				decimals,
+				deposit,
+				existential_deposit,
+			);
+			Ok(())
+		}
+
+		/// Register `asset_id` for `issuer` without a deposit.
+		#[pallet::weight(T::WeightInfo::force_register())]
+		pub fn force_register(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			#[pallet::compact] existential_deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let issuer = T::Lookup::lookup(issuer)?;
+			Self::ensure_new_asset(asset_id, &name, &symbol, existential_deposit)?;
+			Self::do_register(
+				asset_id,
+				issuer,
+				name,
+				symbol,
+				decimals,
+				Zero::zero(),
+				existential_deposit,
+			);
+			Ok(())
+		}
+
+		/// Reject transfers of an active asset. May be called by the issuer or governance.
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			Self::set_status(origin, asset_id, AssetStatus::Active, AssetStatus::Frozen)?;
+			Self::deposit_event(Event::Frozen(asset_id));
+			Ok(())
+		}
+
+		/// Allow transfers of a frozen asset again. May be called by the issuer or governance.
+		#[pallet::weight(T::WeightInfo::thaw())]
+		pub fn thaw(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			Self::set_status(origin, asset_id, AssetStatus::Frozen, AssetStatus::Active)?;
+			Self::deposit_event(Event::Thawed(asset_id));
+			Ok(())
+		}
+
+		/// Retire an asset without issued units and return the deposit to the issuer. May be
+		/// called by the issuer or governance.
+		#[pallet::weight(T::WeightInfo::retire())]
+		pub fn retire(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			Assets::<T>::try_mutate(asset_id, |asset| -> DispatchResult {
+				let asset = asset.as_mut().ok_or(Error::<T>::UnknownAsset)?;
+				Self::ensure_manager(origin, &asset.issuer)?;
+				ensure!(asset.status != AssetStatus::Retired, Error::<T>::AssetRetired);
+				ensure!(T::Assets::total_issuance(asset_id).is_zero(), Error::<T>::AssetInUse);
+				T::Currency::unreserve(&asset.issuer, asset.deposit);
+				asset.deposit = Zero::zero();
+				asset.status = AssetStatus::Retired;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::Retired(asset_id));
+			Ok(())
+		}
+
+		/// Hand the right to freeze, thaw and retire `asset_id` to `issuer`, returning the deposit
+		/// of the previous issuer. Governance revokes the rights of an issuer by taking them over
+		/// with an account it controls, such as the treasury.
+		#[pallet::weight(T::WeightInfo::set_issuer())]
+		pub fn set_issuer(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			issuer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let issuer = T::Lookup::lookup(issuer)?;
+			Assets::<T>::try_mutate(asset_id, |asset| -> DispatchResult {
+				let asset = asset.as_mut().ok_or(Error::<T>::UnknownAsset)?;
+				ensure!(asset.status != AssetStatus::Retired, Error::<T>::AssetRetired);
+				T::Currency::unreserve(&asset.issuer, asset.deposit);
+				asset.deposit = Zero::zero();
+				asset.issuer = issuer.clone();
+				Ok(())
+			})?;
+			Self::deposit_event(Event::IssuerChanged(asset_id, issuer));
+			Ok(())
+		}
+
+		/// Set the minimum balance of accounts holding `asset_id`.
+		#[pallet::weight(T::WeightInfo::set_existential_deposit())]
+		pub fn set_existential_deposit(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			#[pallet::compact] existential_deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				existential_deposit <= T::MaxExistentialDeposit::get(),
+				Error::<T>::ExistentialDepositTooHigh
+			);
+			Assets::<T>::try_mutate(asset_id, |asset| -> DispatchResult {
+				let asset = asset.as_mut().ok_or(Error::<T>::UnknownAsset)?;
+				ensure!(asset.status != AssetStatus::Retired, Error::<T>::AssetRetired);
+				asset.existential_deposit = existential_deposit;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::ExistentialDepositSet(asset_id, existential_deposit));
+			Ok(())
+		}
+
+		/// Exempt `who` from the removal of `orml_tokens` dust.
+		#[pallet::weight(T::WeightInfo::add_to_dust_whitelist())]
+		pub fn add_to_dust_whitelist(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(!DustRemovalWhitelist::<T>::contains_key(&who), Error::<T>::AlreadyWhitelisted);
+			DustRemovalWhitelist::<T>::insert(&who, ());
+			Self::deposit_event(Event::DustWhitelistAdded(who));
+			Ok(())
+		}
+
+		/// Let the dust of `who` be removed again.
+		#[pallet::weight(T::WeightInfo::remove_from_dust_whitelist())]
+		pub fn remove_from_dust_whitelist(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(DustRemovalWhitelist::<T>::contains_key(&who), Error::<T>::NotWhitelisted);
+			DustRemovalWhitelist::<T>::remove(&who);
+			Self::deposit_event(Event::DustWhitelistRemoved(who));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn ensure_new_asset(
+			asset_id: AssetId,
+			name: &[u8],
+			symbol: &[u8],
+			existential_deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(asset_id != AssetId::POLKADEX, Error::<T>::NativeAsset);
+			ensure!(!Assets::<T>::contains_key(asset_id), Error::<T>::AssetExists);
+			ensure!(
+				name.len() as u32 <= T::StringLimit::get() &&
+					symbol.len() as u32 <= T::StringLimit::get(),
+				Error::<T>::BadMetadata
+			);
+			ensure!(
+				existential_deposit <= T::MaxExistentialDeposit::get(),
+				Error::<T>::ExistentialDepositTooHigh
+			);
+			Ok(())
+		}
+
+		fn do_register(
+			asset_id: AssetId,
+			issuer: T::AccountId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			deposit: BalanceOf<T>,
+			existential_deposit: BalanceOf<T>,
+		) {
+			Assets::<T>::insert(
+				asset_id,
+				AssetMetadata {
+					name,
+					symbol,
+					decimals,
+					issuer: issuer.clone(),
+					deposit,
+					existential_deposit,
+					status: AssetStatus::Active,
+				},
+			);
+			Self::deposit_event(Event::Registered(asset_id, issuer));
+		}
+
+		/// Ensures `origin` is governance or the signed `issuer`.
+		fn ensure_manager(origin: OriginFor<T>, issuer: &T::AccountId) -> DispatchResult {
+			if let Err(origin) = T::GovernanceOrigin::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				ensure!(&who == issuer, Error::<T>::NoPermission);
+			}
+			Ok(())
+		}
+
+		fn set_status(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			from: AssetStatus,
+			to: AssetStatus,
+		) -> DispatchResult {
+			Assets::<T>::try_mutate(asset_id, |asset| -> DispatchResult {
+				let asset = asset.as_mut().ok_or(Error::<T>::UnknownAsset)?;
+				Self::ensure_manager(origin, &asset.issuer)?;
+				ensure!(
+					asset.status == from,
+					match from {
+						AssetStatus::Frozen => Error::<T>::AssetNotFrozen,
+						_ => Error::<T>::AssetNotActive,
+					}
+				);
+				asset.status = to;
+				Ok(())
+			})
+		}
+
+		/// Returns the existential deposit of a registered asset.
+		pub fn existential_deposit(asset_id: AssetId) -> Option<BalanceOf<T>> {
+			Assets::<T>::get(asset_id).map(|asset| asset.existential_deposit)
+		}
+
+		/// Returns true if `asset_id` is registered and may be transferred.
+		pub fn is_active(asset_id: AssetId) -> bool {
+			Assets::<T>::get(asset_id).map_or(false, |asset| asset.status == AssetStatus::Active)
+		}
+	}
+}
+
+/// Accounts added to the dust removal whitelist by governance.
+impl<T: Config> Contains<T::AccountId> for Pallet<T> {
+	fn contains(who: &T::AccountId) -> bool {
+		DustRemovalWhitelist::<T>::contains_key(who)
+	}
+}
+
+/// `MultiCurrency` adapter over `Assets` that rejects moving balances of assets which are not
+/// active in the registry. Locks may still be changed and slashes still apply.
+pub struct FreezableAssets<T, Assets>(PhantomData<(T, Assets)>);
+
+impl<T: Config, Assets> FreezableAssets<T, Assets> {
+	fn ensure_active(asset_id: AssetId) -> DispatchResult {
+		ensure!(Pallet::<T>::is_active(asset_id), Error::<T>::AssetNotActive);
+		Ok(())
+	}
+}
+
+impl<T, Assets> MultiCurrency<T::AccountId> for FreezableAssets<T, Assets>
+where
+	T: Config,
+	Assets: MultiCurrency<T::AccountId, CurrencyId = AssetId>,
+{
+	type CurrencyId = AssetId;
+	type Balance = Assets::Balance;
+
+	fn minimum_balance(asset_id: AssetId) -> Self::Balance {
+		Assets::minimum_balance(asset_id)
+	}
+
+	fn total_issuance(asset_id: AssetId) -> Self::Balance {
+		Assets::total_issuance(asset_id)
+	}
+
+	fn total_balance(asset_id: AssetId, who: &T::AccountId) -> Self::Balance {
+		Assets::total_balance(asset_id, who)
+	}
+
+	fn free_balance(asset_id: AssetId, who: &T::AccountId) -> Self::Balance {
+		Assets::free_balance(asset_id, who)
+	}
+
+	fn ensure_can_withdraw(
+		asset_id: AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::ensure_active(asset_id)?;
+		Assets::ensure_can_withdraw(asset_id, who, amount)
+	}
+
+	fn transfer(
+		asset_id: AssetId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::ensure_active(asset_id)?;
+		Assets::transfer(asset_id, from, to, amount)
+	}
+
+	fn deposit(asset_id: AssetId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::ensure_active(asset_id)?;
+		Assets::deposit(asset_id, who, amount)
+	}
+
+	fn withdraw(asset_id: AssetId, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::ensure_active(asset_id)?;
+		Assets::withdraw(asset_id, who, amount)
+	}
+
+	fn can_slash(asset_id: AssetId, who: &T::AccountId, value: Self::Balance) -> bool {
+		Assets::can_slash(asset_id, who, value)
+	}
+
+	fn slash(asset_id: AssetId, who: &T::AccountId, amount: Self::Balance) -> Self::Balance {
+		Assets::slash(asset_id, who, amount)
+	}
+}
+
+impl<T, Assets> MultiCurrencyExtended<T::AccountId> for FreezableAssets<T, Assets>
+where
+	T: Config,
+	Assets: MultiCurrencyExtended<T::AccountId, CurrencyId = AssetId>,
+{
+	type Amount = Assets::Amount;
+
+	fn update_balance(
+		asset_id: AssetId,
+		who: &T::AccountId,
+		by_amount: Self::Amount,
+	) -> DispatchResult {
+		Self::ensure_active(asset_id)?;
+		Assets::update_balance(asset_id, who, by_amount)
+	}
+}
+
+impl<T, Assets> MultiLockableCurrency<T::AccountId> for FreezableAssets<T, Assets>
+where
+	T: Config,
+	Assets: MultiLockableCurrency<T::AccountId, CurrencyId = AssetId>,
+{
+	type Moment = Assets::Moment;
+
+	fn set_lock(
+		lock_id: LockIdentifier,
+		asset_id: AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Assets::set_lock(lock_id, asset_id, who, amount)
+	}
+
+	fn extend_lock(
+		lock_id: LockIdentifier,
+		asset_id: AssetId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Assets::extend_lock(lock_id, asset_id, who, amount)
+	}
+
+	fn remove_lock(
+		lock_id: LockIdentifier,
+		asset_id: AssetId,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		Assets::remove_lock(lock_id, asset_id, who)
+	}
+}
+
+impl<T, Assets> MultiReservableCurrency<T::AccountId> for FreezableAssets<T, Assets>
+where
+	T: Config,
+	Assets: MultiReservableCurrency<T::AccountId, CurrencyId = AssetId>,
+{
+	fn can_reserve(asset_id: AssetId, who: &T::AccountId, value: Self::Balance) -> bool {
+		Pallet::<T>::is_active(asset_id) && Assets::can_reserve(asset_id, who, value)
+	}
+
+	fn slash_reserved(
+		asset_id: AssetId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> Self::Balance {
+		Assets::slash_reserved(asset_id, who, value)
+	}
+
+	fn reserved_balance(asset_id: AssetId, who: &T::AccountId) -> Self::Balance {
+		Assets::reserved_balance(asset_id, who)
+	}
+
+	fn reserve(asset_id: AssetId, who: &T::AccountId, value: Self::Balance) -> DispatchResult {
+		Self::ensure_active(asset_id)?;
+		Assets::reserve(asset_id, who, value)
+	}
+
+	fn unreserve(asset_id: AssetId, who: &T::AccountId, value: Self::Balance) -> Self::Balance {
+		Assets::unreserve(asset_id, who, value)
+	}
+
+	fn repatriate_reserved(
+		asset_id: AssetId,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::ensure_active(asset_id)?;
+		Assets::repatriate_reserved(asset_id, slashed, beneficiary, value, status)
+	}
+}
@@ -0,0 +1,365 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, AssetStatus, Error};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{BalanceStatus, Contains, Get},
+};
+use orml_traits::{
+	GetByKey, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency,
+};
+use polkadex_primitives::assets::AssetId;
+use sp_runtime::DispatchError;
+
+const ASSET: AssetId = AssetId::Asset(2);
+
+fn last_event() -> Event {
+	System::events().pop().expect("an event was deposited").event
+}
+
+fn existential_deposit(asset_id: AssetId) -> Balance {
+	<<Test as orml_tokens::Config>::ExistentialDeposits as GetByKey<_, _>>::get(&asset_id)
+}
+
+fn status(asset_id: AssetId) -> Option<AssetStatus> {
+	AssetRegistry::assets(asset_id).map(|asset| asset.status)
+}
+
+#[test]
+fn genesis_assets_are_registered_without_deposit() {
+	new_test_ext().execute_with(|| {
+		let asset = AssetRegistry::assets(GENESIS_ASSET).unwrap();
+		assert_eq!(asset.symbol, b"GEN".to_vec());
+		assert_eq!(asset.issuer, ALICE);
+		assert_eq!(asset.deposit, 0);
+		assert_eq!(AssetRegistry::existential_deposit(GENESIS_ASSET), Some(10));
+		assert!(AssetRegistry::is_active(GENESIS_ASSET));
+		assert!(!AssetRegistry::is_active(ASSET));
+	});
+}
+
+#[test]
+fn register_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetRegistry::register(
+			Origin::signed(ALICE),
+			ASSET,
+			b"Token".to_vec(),
+			b"TKN".to_vec(),
+			18,
+			5
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 100);
+		let asset = AssetRegistry::assets(ASSET).unwrap();
+		assert_eq!((asset.decimals, asset.deposit, asset.status), (18, 100, AssetStatus::Active));
+		assert_eq!(last_event(), Event::AssetRegistry(crate::Event::Registered(ASSET, ALICE)));
+
+		assert_noop!(
+			AssetRegistry::register(Origin::signed(ALICE), ASSET, vec![], vec![], 18, 5),
+			Error::<Test>::AssetExists
+		);
+		assert_noop!(
+			AssetRegistry::register(
+				Origin::signed(ALICE),
+				AssetId::POLKADEX,
+				vec![],
+				vec![],
+				12,
+				0
+			),
+			Error::<Test>::NativeAsset
+		);
+		assert_noop!(
+			AssetRegistry::register(
+				Origin::signed(ALICE),
+				AssetId::Asset(3),
+				b"Too long name".to_vec(),
+				vec![],
+				12,
+				0
+			),
+			Error::<Test>::BadMetadata
+		);
+		// BOB cannot pay the deposit
+		assert!(AssetRegistry::register(
+			Origin::signed(BOB),
+			AssetId::Asset(3),
+			vec![],
+			vec![],
+			12,
+			0
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn register_rejects_issued_assets() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Tokens::deposit(ASSET, &BOB, 10));
+		assert_noop!(
+			AssetRegistry::register(Origin::signed(ALICE), ASSET, vec![], vec![], 12, 0),
+			Error::<Test>::AssetInUse
+		);
+		// governance may still register assets already in circulation
+		assert_ok!(AssetRegistry::force_register(
+			Origin::root(),
+			ASSET,
+			ALICE,
+			vec![],
+			vec![],
+			12,
+			0
+		));
+	});
+}
+
+#[test]
+fn existential_deposits_are_bounded() {
+	new_test_ext().execute_with(|| {
+		let max = MaxExistentialDeposit::get();
+		assert_noop!(
+			AssetRegistry::register(Origin::signed(ALICE), ASSET, vec![], vec![], 12, max + 1),
+			Error::<Test>::ExistentialDepositTooHigh
+		);
+		assert_noop!(
+			AssetRegistry::force_register(
+				Origin::root(),
+				ASSET,
+				ALICE,
+				vec![],
+				vec![],
+				12,
+				max + 1
+			),
+			Error::<Test>::ExistentialDepositTooHigh
+		);
+		assert_noop!(
+			AssetRegistry::set_existential_deposit(Origin::root(), GENESIS_ASSET, max + 1),
+			Error::<Test>::ExistentialDepositTooHigh
+		);
+		assert_ok!(AssetRegistry::register(Origin::signed(ALICE), ASSET, vec![], vec![], 12, max));
+	});
+}
+
+#[test]
+fn force_register_requires_governance() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::force_register(Origin::signed(ALICE), ASSET, BOB, vec![], vec![], 12, 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(AssetRegistry::force_register(
+			Origin::root(),
+			ASSET,
+			BOB,
+			vec![],
+			vec![],
+			12,
+			0
+		));
+		assert_eq!(AssetRegistry::assets(ASSET).map(|asset| asset.issuer), Some(BOB));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn issuer_and_governance_freeze_and_thaw() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::freeze(Origin::signed(BOB), GENESIS_ASSET),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AssetRegistry::thaw(Origin::root(), GENESIS_ASSET),
+			Error::<Test>::AssetNotFrozen
+		);
+		assert_noop!(AssetRegistry::freeze(Origin::root(), ASSET), Error::<Test>::UnknownAsset);
+
+		assert_ok!(AssetRegistry::freeze(Origin::signed(ALICE), GENESIS_ASSET));
+		assert_eq!(status(GENESIS_ASSET), Some(AssetStatus::Frozen));
+		assert!(!AssetRegistry::is_active(GENESIS_ASSET));
+		assert_eq!(last_event(), Event::AssetRegistry(crate::Event::Frozen(GENESIS_ASSET)));
+		assert_noop!(
+			AssetRegistry::freeze(Origin::root(), GENESIS_ASSET),
+			Error::<Test>::AssetNotActive
+		);
+
+		assert_ok!(AssetRegistry::thaw(Origin::root(), GENESIS_ASSET));
+		assert_eq!(status(GENESIS_ASSET), Some(AssetStatus::Active));
+		assert_eq!(last_event(), Event::AssetRegistry(crate::Event::Thawed(GENESIS_ASSET)));
+	});
+}
+
+#[test]
+fn governance_takes_over_issuer_rights() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetRegistry::register(Origin::signed(ALICE), ASSET, vec![], vec![], 12, 0));
+		assert_eq!(Balances::reserved_balance(ALICE), 100);
+
+		assert_noop!(
+			AssetRegistry::set_issuer(Origin::signed(ALICE), ASSET, BOB),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetRegistry::set_issuer(Origin::root(), AssetId::Asset(3), BOB),
+			Error::<Test>::UnknownAsset
+		);
+		assert_ok!(AssetRegistry::set_issuer(Origin::root(), ASSET, BOB));
+		assert_eq!(last_event(), Event::AssetRegistry(crate::Event::IssuerChanged(ASSET, BOB)));
+		let asset = AssetRegistry::assets(ASSET).unwrap();
+		assert_eq!((asset.issuer, asset.deposit), (BOB, 0));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+
+		// The previous issuer lost its rights.
+		assert_noop!(
+			AssetRegistry::freeze(Origin::signed(ALICE), ASSET),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(AssetRegistry::freeze(Origin::signed(BOB), ASSET));
+
+		assert_ok!(AssetRegistry::retire(Origin::root(), ASSET));
+		assert_noop!(
+			AssetRegistry::set_issuer(Origin::root(), ASSET, ALICE),
+			Error::<Test>::AssetRetired
+		);
+	});
+}
+
+#[test]
+fn frozen_assets_do_not_move_through_the_adapter() {
+	type Assets = crate::FreezableAssets<Test, Tokens>;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::deposit(GENESIS_ASSET, &ALICE, 100));
+		assert_ok!(Assets::transfer(GENESIS_ASSET, &ALICE, &BOB, 20));
+		assert_ok!(Assets::reserve(GENESIS_ASSET, &ALICE, 10));
+		// unregistered assets never move
+		assert_noop!(Assets::deposit(ASSET, &ALICE, 100), Error::<Test>::AssetNotActive);
+
+		assert_ok!(AssetRegistry::freeze(Origin::root(), GENESIS_ASSET));
+		assert_noop!(
+			Assets::transfer(GENESIS_ASSET, &ALICE, &BOB, 20),
+			Error::<Test>::AssetNotActive
+		);
+		assert_noop!(Assets::deposit(GENESIS_ASSET, &ALICE, 20), Error::<Test>::AssetNotActive);
+		assert_noop!(Assets::withdraw(GENESIS_ASSET, &ALICE, 20), Error::<Test>::AssetNotActive);
+		assert_noop!(
+			Assets::update_balance(GENESIS_ASSET, &ALICE, -20),
+			Error::<Test>::AssetNotActive
+		);
+		assert_noop!(Assets::reserve(GENESIS_ASSET, &ALICE, 10), Error::<Test>::AssetNotActive);
+		assert_noop!(
+			Assets::repatriate_reserved(GENESIS_ASSET, &ALICE, &BOB, 10, BalanceStatus::Free),
+			Error::<Test>::AssetNotActive
+		);
+		assert!(!Assets::can_reserve(GENESIS_ASSET, &ALICE, 10));
+		// locks may still be released
+		assert_ok!(Assets::set_lock(*b"testlock", GENESIS_ASSET, &ALICE, 10));
+		assert_ok!(Assets::remove_lock(*b"testlock", GENESIS_ASSET, &ALICE));
+
+		assert_ok!(AssetRegistry::thaw(Origin::root(), GENESIS_ASSET));
+		assert_ok!(Assets::transfer(GENESIS_ASSET, &ALICE, &BOB, 20));
+		assert_eq!(Tokens::free_balance(GENESIS_ASSET, &BOB), 40);
+	});
+}
+
+#[test]
+fn retire_requires_no_issued_units() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetRegistry::register(Origin::signed(ALICE), ASSET, vec![], vec![], 12, 0));
+		assert_ok!(Tokens::deposit(ASSET, &BOB, 10));
+		assert_noop!(
+			AssetRegistry::retire(Origin::signed(BOB), ASSET),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AssetRegistry::retire(Origin::signed(ALICE), ASSET),
+			Error::<Test>::AssetInUse
+		);
+
+		assert_ok!(Tokens::withdraw(ASSET, &BOB, 10));
+		assert_ok!(AssetRegistry::retire(Origin::signed(ALICE), ASSET));
+		assert_eq!(status(ASSET), Some(AssetStatus::Retired));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(last_event(), Event::AssetRegistry(crate::Event::Retired(ASSET)));
+
+		assert_noop!(AssetRegistry::retire(Origin::root(), ASSET), Error::<Test>::AssetRetired);
+		assert_noop!(AssetRegistry::thaw(Origin::root(), ASSET), Error::<Test>::AssetNotFrozen);
+		assert_noop!(
+			AssetRegistry::register(Origin::signed(ALICE), ASSET, vec![], vec![], 12, 0),
+			Error::<Test>::AssetExists
+		);
+		assert_noop!(
+			AssetRegistry::set_existential_deposit(Origin::root(), ASSET, 1),
+			Error::<Test>::AssetRetired
+		);
+	});
+}
+
+#[test]
+fn existential_deposits_are_read_by_tokens() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(existential_deposit(GENESIS_ASSET), 10);
+		// unregistered assets keep a zero existential deposit
+		assert_eq!(existential_deposit(ASSET), 0);
+
+		assert_noop!(
+			AssetRegistry::set_existential_deposit(Origin::signed(ALICE), GENESIS_ASSET, 1),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(AssetRegistry::set_existential_deposit(Origin::root(), GENESIS_ASSET, 1));
+		assert_eq!(
+			last_event(),
+			Event::AssetRegistry(crate::Event::ExistentialDepositSet(GENESIS_ASSET, 1))
+		);
+		assert_eq!(existential_deposit(GENESIS_ASSET), 1);
+	});
+}
+
+#[test]
+fn dust_whitelist_is_managed_by_governance() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetRegistry::add_to_dust_whitelist(Origin::signed(ALICE), BOB),
+			DispatchError::BadOrigin
+		);
+		assert!(!AssetRegistry::contains(&BOB));
+
+		assert_ok!(AssetRegistry::add_to_dust_whitelist(Origin::root(), BOB));
+		assert!(AssetRegistry::contains(&BOB));
+		assert_eq!(last_event(), Event::AssetRegistry(crate::Event::DustWhitelistAdded(BOB)));
+		assert_noop!(
+			AssetRegistry::add_to_dust_whitelist(Origin::root(), BOB),
+			Error::<Test>::AlreadyWhitelisted
+		);
+
+		assert_noop!(
+			AssetRegistry::remove_from_dust_whitelist(Origin::signed(BOB), BOB),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(AssetRegistry::remove_from_dust_whitelist(Origin::root(), BOB));
+		assert!(!AssetRegistry::contains(&BOB));
+		assert_eq!(last_event(), Event::AssetRegistry(crate::Event::DustWhitelistRemoved(BOB)));
+		assert_noop!(
+			AssetRegistry::remove_from_dust_whitelist(Origin::root(), BOB),
+			Error::<Test>::NotWhitelisted
+		);
+	});
+}
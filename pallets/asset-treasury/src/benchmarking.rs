@@ -0,0 +1,144 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for asset-treasury-pallet
+
+use super::*;
+use crate::Pallet as AssetTreasury;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::{Saturating, StaticLookup, Zero};
+
+const SEED: u32 = 0;
+const ASSET: AssetId = AssetId::Asset(1);
+
+/// Returns 100 times the native existential deposit.
+fn amount<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance().saturating_mul(100u32.into())
+}
+
+/// Credits `who` with `reserved` on top of the native existential deposit.
+fn fund<T: Config>(who: &T::AccountId, reserved: BalanceOf<T>) {
+	T::Currency::make_free_balance_be(who, reserved.saturating_add(T::Currency::minimum_balance()));
+}
+
+/// Credits the treasury with the benchmarked asset.
+fn fund_treasury<T: Config>() -> Result<(), &'static str> {
+	T::BenchmarkHelper::create_asset(ASSET);
+	T::Assets::deposit(ASSET, &T::TreasuryAccount::get(), amount::<T>())?;
+	Ok(())
+}
+
+/// Proposes a spend to a fresh beneficiary from a funded proposer.
+fn propose<T: Config>() -> Result<T::AccountId, &'static str> {
+	let proposer: T::AccountId = account("proposer", 0, SEED);
+	let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+	T::BenchmarkHelper::create_asset(ASSET);
+	fund::<T>(&proposer, T::ProposalBond::get());
+	AssetTreasury::<T>::propose_spend(
+		RawOrigin::Signed(proposer).into(),
+		ASSET,
+		amount::<T>(),
+		T::Lookup::unlookup(beneficiary.clone()),
+	)?;
+	Ok(beneficiary)
+}
+
+/// Offers to buy the benchmarked asset from a funded buyer.
+fn offer<T: Config>(buyer: &T::AccountId) -> Result<(), &'static str> {
+	T::BenchmarkHelper::create_asset(ASSET);
+	fund::<T>(buyer, amount::<T>());
+	AssetTreasury::<T>::offer_conversion(
+		RawOrigin::Signed(buyer.clone()).into(),
+		ASSET,
+		amount::<T>(),
+		amount::<T>(),
+	)?;
+	Ok(())
+}
+
+benchmarks! {
+	propose_spend {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		T::BenchmarkHelper::create_asset(ASSET);
+		fund::<T>(&caller, T::ProposalBond::get());
+	}: _(RawOrigin::Signed(caller), ASSET, amount::<T>(), T::Lookup::unlookup(beneficiary))
+	verify {
+		assert!(AssetTreasury::<T>::proposals(0).is_some());
+	}
+
+	reject_proposal {
+		propose::<T>()?;
+		let origin = T::RejectOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0)
+	verify {
+		assert!(AssetTreasury::<T>::proposals(0).is_none());
+	}
+
+	approve_proposal {
+		let beneficiary = propose::<T>()?;
+		fund_treasury::<T>()?;
+		let origin = T::ApproveOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0)
+	verify {
+		assert!(AssetTreasury::<T>::proposals(0).is_none());
+		assert_eq!(T::Assets::free_balance(ASSET, &beneficiary), amount::<T>());
+	}
+
+	burn {
+		fund_treasury::<T>()?;
+		let held = T::Assets::free_balance(ASSET, &T::TreasuryAccount::get());
+		let origin = T::BurnOrigin::successful_origin();
+	}: _<T::Origin>(origin, ASSET, held)
+	verify {
+		assert!(T::Assets::free_balance(ASSET, &T::TreasuryAccount::get()).is_zero());
+	}
+
+	offer_conversion {
+		let caller: T::AccountId = whitelisted_caller();
+		T::BenchmarkHelper::create_asset(ASSET);
+		fund::<T>(&caller, amount::<T>());
+	}: _(RawOrigin::Signed(caller), ASSET, amount::<T>(), amount::<T>())
+	verify {
+		assert!(AssetTreasury::<T>::offers(0).is_some());
+	}
+
+	cancel_offer {
+		let caller: T::AccountId = whitelisted_caller();
+		offer::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(AssetTreasury::<T>::offers(0).is_none());
+	}
+
+	accept_offer {
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		offer::<T>(&buyer)?;
+		fund_treasury::<T>()?;
+		let origin = T::ApproveOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0)
+	verify {
+		assert!(AssetTreasury::<T>::offers(0).is_none());
+		assert_eq!(T::Assets::free_balance(ASSET, &buyer), amount::<T>());
+	}
+}
+
+impl_benchmark_test_suite!(AssetTreasury, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,379 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+//! # Asset Treasury Pallet
+//!
+//! Spends the treasury's holdings in any `AssetId`, such as the dust `orml_tokens` transfers to
+//! the treasury account. Anyone may propose to pay an asset to a beneficiary, reserving a fixed
+//! native bond since asset amounts cannot be priced in PDEX. `ApproveOrigin` pays an approved
+//! proposal out of `TreasuryAccount` and returns the bond, while `RejectOrigin` slashes it.
+//!
+//! Accumulated assets can also be burnt by `BurnOrigin`, or converted into native PDEX: buyers
+//! offer a PDEX price for an amount of an asset, reserving the price, and `ApproveOrigin` accepts
+//! an offer by swapping the asset for the reserved PDEX.
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode};
+use frame_support::traits::Currency;
+use polkadex_primitives::assets::AssetId;
+use sp_runtime::RuntimeDebug;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+/// Index of an asset spend proposal.
+pub type ProposalIndex = u32;
+/// Index of a conversion offer.
+pub type OfferIndex = u32;
+
+/// Balance type of the native currency and of the treasury assets.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+/// Imbalance of slashed proposal bonds.
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+/// Asset spend proposal of the pallet.
+pub type AssetSpendOf<T> = AssetSpend<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+/// Conversion offer of the pallet.
+pub type ConversionOfferOf<T> =
+	ConversionOffer<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+/// A proposal to pay an asset held by the treasury.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct AssetSpend<AccountId, Balance> {
+	/// Account that proposed the spend
+	pub proposer: AccountId,
+	/// Account receiving the asset
+	pub beneficiary: AccountId,
+	/// Asset paid out
+	pub asset_id: AssetId,
+	/// Amount of the asset paid out
+	pub value: Balance,
+	/// Native amount reserved from the proposer
+	pub bond: Balance,
+}
+
+/// An offer to buy an asset held by the treasury for native PDEX.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct ConversionOffer<AccountId, Balance> {
+	/// Account buying the asset, whose PDEX price is reserved
+	pub buyer: AccountId,
+	/// Asset bought from the treasury
+	pub asset_id: AssetId,
+	/// Amount of the asset bought
+	pub amount: Balance,
+	/// Native amount paid to the treasury
+	pub price: Balance,
+}
+
+/// Prepares the state the benchmarks of this pallet run in.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Makes `asset_id` usable through the pallet's currency.
+	fn create_asset(asset_id: AssetId);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper for () {
+	fn create_asset(_asset_id: AssetId) {}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{BalanceStatus, OnUnbalanced, ReservableCurrency},
+		transactional,
+	};
+	use frame_system::pallet_prelude::*;
+	use orml_traits::MultiCurrency;
+	use sp_runtime::traits::{StaticLookup, Zero};
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Native currency of proposal bonds and conversion prices
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Module holding the treasury assets
+		type Assets: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = BalanceOf<Self>>;
+		/// Account holding the treasury assets
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+		/// Origin that approves spends and accepts conversion offers
+		type ApproveOrigin: EnsureOrigin<Self::Origin>;
+		/// Origin that rejects spends
+		type RejectOrigin: EnsureOrigin<Self::Origin>;
+		/// Origin that burns treasury assets
+		type BurnOrigin: EnsureOrigin<Self::Origin>;
+		/// Handler of slashed proposal bonds
+		type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// Native amount reserved as proposal bond
+		#[pallet::constant]
+		type ProposalBond: Get<BalanceOf<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// Creates the asset used by the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+	}
+
+	/// Number of proposals ever made
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_count)]
+	pub type ProposalCount<T> = StorageValue<_, ProposalIndex, ValueQuery>;
+
+	/// Asset spends awaiting approval or rejection
+	#[pallet::storage]
+	#[pallet::getter(fn proposals)]
+	pub type Proposals<T: Config> =
+		StorageMap<_, Twox64Concat, ProposalIndex, AssetSpendOf<T>, OptionQuery>;
+
+	/// Number of conversion offers ever made
+	#[pallet::storage]
+	#[pallet::getter(fn offer_count)]
+	pub type OfferCount<T> = StorageValue<_, OfferIndex, ValueQuery>;
+
+	/// Conversion offers awaiting acceptance or cancellation
+	#[pallet::storage]
+	#[pallet::getter(fn offers)]
+	pub type Offers<T: Config> =
+		StorageMap<_, Twox64Concat, OfferIndex, ConversionOfferOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance")]
+	pub enum Event<T: Config> {
+		/// An asset spend was proposed. \[proposal_index\]
+		Proposed(ProposalIndex),
+		/// An asset spend was rejected and its bond slashed. \[proposal_index, slashed\]
+		Rejected(ProposalIndex, BalanceOf<T>),
+		/// An asset spend was paid. \[proposal_index, asset_id, beneficiary, value\]
+		Paid(ProposalIndex, AssetId, T::AccountId, BalanceOf<T>),
+		/// Treasury assets were burnt. \[asset_id, amount\]
+		Burned(AssetId, BalanceOf<T>),
+		/// A conversion of treasury assets was offered. \[offer_index, buyer\]
+		ConversionOffered(OfferIndex, T::AccountId),
+		/// A conversion offer was cancelled by its buyer. \[offer_index\]
+		OfferCancelled(OfferIndex),
+		/// Treasury assets were converted into native PDEX. \[offer_index, asset_id, amount,
+		/// price\]
+		Converted(OfferIndex, AssetId, BalanceOf<T>, BalanceOf<T>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The proposer cannot reserve the proposal bond.
+		InsufficientProposersBalance,
+		/// The buyer cannot reserve the price of the offer.
+		InsufficientBuyersBalance,
+		/// No proposal or offer has the given index.
+		InvalidIndex,
+		/// The amount is zero.
+		ZeroAmount,
+		/// Native PDEX cannot be spent, burnt or converted by this pallet.
+		NativeAsset,
+		/// The treasury does not hold enough of the asset.
+		InsufficientTreasuryBalance,
+		/// The offer was made by another account.
+		NotBuyer,
+		/// Part of the reserved price of the offer was slashed.
+		OfferUnderfunded,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Propose to pay `value` of `asset_id` to `beneficiary`, reserving the proposal bond.
+		#[pallet::weight(T::WeightInfo::propose_spend())]
+		pub fn propose_spend(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			#[pallet::compact] value: BalanceOf<T>,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			ensure!(asset_id != AssetId::POLKADEX, Error::<T>::NativeAsset);
+			ensure!(!value.is_zero(), Error::<T>::ZeroAmount);
+
+			let bond = T::ProposalBond::get();
+			T::Currency::reserve(&proposer, bond)
+				.map_err(|_| Error::<T>::InsufficientProposersBalance)?;
+
+			let index = Self::proposal_count();
+			ProposalCount::<T>::put(index + 1);
+			Proposals::<T>::insert(
+				index,
+				AssetSpend { proposer, beneficiary, asset_id, value, bond },
+			);
+			Self::deposit_event(Event::Proposed(index));
+			Ok(())
+		}
+
+		/// Reject a proposed spend, slashing its bond.
+		#[pallet::weight(T::WeightInfo::reject_proposal())]
+		pub fn reject_proposal(
+			origin: OriginFor<T>,
+			#[pallet::compact] proposal_id: ProposalIndex,
+		) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			let proposal = Proposals::<T>::take(proposal_id).ok_or(Error::<T>::InvalidIndex)?;
+
+			let imbalance = T::Currency::slash_reserved(&proposal.proposer, proposal.bond).0;
+			T::OnSlash::on_unbalanced(imbalance);
+			Self::deposit_event(Event::Rejected(proposal_id, proposal.bond));
+			Ok(())
+		}
+
+		/// Approve a proposed spend, paying it out of the treasury and returning the bond.
+		#[pallet::weight(T::WeightInfo::approve_proposal())]
+		#[transactional]
+		pub fn approve_proposal(
+			origin: OriginFor<T>,
+			#[pallet::compact] proposal_id: ProposalIndex,
+		) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			let proposal = Proposals::<T>::take(proposal_id).ok_or(Error::<T>::InvalidIndex)?;
+			ensure!(proposal.asset_id != AssetId::POLKADEX, Error::<T>::NativeAsset);
+
+			T::Currency::unreserve(&proposal.proposer, proposal.bond);
+			T::Assets::transfer(
+				proposal.asset_id,
+				&T::TreasuryAccount::get(),
+				&proposal.beneficiary,
+				proposal.value,
+			)?;
+			Self::deposit_event(Event::Paid(
+				proposal_id,
+				proposal.asset_id,
+				proposal.beneficiary,
+				proposal.value,
+			));
+			Ok(())
+		}
+
+		/// Burn `amount` of `asset_id` held by the treasury.
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::BurnOrigin::ensure_origin(origin)?;
+			ensure!(asset_id != AssetId::POLKADEX, Error::<T>::NativeAsset);
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			T::Assets::withdraw(asset_id, &T::TreasuryAccount::get(), amount)
+				.map_err(|_| Error::<T>::InsufficientTreasuryBalance)?;
+			Self::deposit_event(Event::Burned(asset_id, amount));
+			Ok(())
+		}
+
+		/// Offer to buy `amount` of `asset_id` from the treasury for `price` PDEX, reserving the
+		/// price until the offer is accepted or cancelled.
+		#[pallet::weight(T::WeightInfo::offer_conversion())]
+		pub fn offer_conversion(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			#[pallet::compact] amount: BalanceOf<T>,
+			#[pallet::compact] price: BalanceOf<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			ensure!(asset_id != AssetId::POLKADEX, Error::<T>::NativeAsset);
+			ensure!(!amount.is_zero() && !price.is_zero(), Error::<T>::ZeroAmount);
+
+			T::Currency::reserve(&buyer, price)
+				.map_err(|_| Error::<T>::InsufficientBuyersBalance)?;
+
+			let index = Self::offer_count();
+			OfferCount::<T>::put(index + 1);
+			Offers::<T>::insert(
+				index,
+				ConversionOffer { buyer: buyer.clone(), asset_id, amount, price },
+			);
+			Self::deposit_event(Event::ConversionOffered(index, buyer));
+			Ok(())
+		}
+
+		/// Cancel an offer of the sender, unreserving its price.
+		#[pallet::weight(T::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(
+			origin: OriginFor<T>,
+			#[pallet::compact] offer_id: OfferIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = Self::offers(offer_id).ok_or(Error::<T>::InvalidIndex)?;
+			ensure!(offer.buyer == who, Error::<T>::NotBuyer);
+
+			Offers::<T>::remove(offer_id);
+			T::Currency::unreserve(&offer.buyer, offer.price);
+			Self::deposit_event(Event::OfferCancelled(offer_id));
+			Ok(())
+		}
+
+		/// Accept a conversion offer, paying its asset out of the treasury for the reserved
+		/// price.
+		#[pallet::weight(T::WeightInfo::accept_offer())]
+		#[transactional]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			#[pallet::compact] offer_id: OfferIndex,
+		) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			let offer = Offers::<T>::take(offer_id).ok_or(Error::<T>::InvalidIndex)?;
+			let treasury = T::TreasuryAccount::get();
+
+			T::Assets::transfer(offer.asset_id, &treasury, &offer.buyer, offer.amount)
+				.map_err(|_| Error::<T>::InsufficientTreasuryBalance)?;
+			let unpaid = T::Currency::repatriate_reserved(
+				&offer.buyer,
+				&treasury,
+				offer.price,
+				BalanceStatus::Free,
+			)?;
+			ensure!(unpaid.is_zero(), Error::<T>::OfferUnderfunded);
+			Self::deposit_event(Event::Converted(
+				offer_id,
+				offer.asset_id,
+				offer.amount,
+				offer.price,
+			));
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,229 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, AssetSpend, ConversionOffer, Error, Proposals};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use polkadex_primitives::assets::AssetId;
+use sp_runtime::DispatchError;
+
+fn last_event() -> Event {
+	System::events().pop().expect("an event was deposited").event
+}
+
+#[test]
+fn propose_spend_reserves_bond() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetTreasury::propose_spend(Origin::signed(ALICE), ASSET, 400, BOB));
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert_eq!(AssetTreasury::proposal_count(), 1);
+		assert_eq!(
+			AssetTreasury::proposals(0),
+			Some(AssetSpend {
+				proposer: ALICE,
+				beneficiary: BOB,
+				asset_id: ASSET,
+				value: 400,
+				bond: 10
+			})
+		);
+		assert_eq!(last_event(), Event::AssetTreasury(crate::Event::Proposed(0)));
+
+		assert_noop!(
+			AssetTreasury::propose_spend(Origin::signed(ALICE), ASSET, 0, BOB),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			AssetTreasury::propose_spend(Origin::signed(ALICE), AssetId::POLKADEX, 400, BOB),
+			Error::<Test>::NativeAsset
+		);
+		assert_noop!(
+			AssetTreasury::propose_spend(Origin::signed(BOB), ASSET, 400, ALICE),
+			Error::<Test>::InsufficientProposersBalance
+		);
+	});
+}
+
+#[test]
+fn approve_proposal_pays_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetTreasury::propose_spend(Origin::signed(ALICE), ASSET, 400, BOB));
+		assert_noop!(
+			AssetTreasury::approve_proposal(Origin::signed(ALICE), 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetTreasury::approve_proposal(Origin::root(), 1),
+			Error::<Test>::InvalidIndex
+		);
+
+		assert_ok!(AssetTreasury::approve_proposal(Origin::root(), 0));
+		assert_eq!(AssetTreasury::proposals(0), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		assert_eq!(Tokens::free_balance(ASSET, &TREASURY), 600);
+		assert_eq!(Tokens::free_balance(ASSET, &BOB), 400);
+		assert_eq!(last_event(), Event::AssetTreasury(crate::Event::Paid(0, ASSET, BOB, 400)));
+	});
+}
+
+#[test]
+fn approve_proposal_rejects_native_spends() {
+	new_test_ext().execute_with(|| {
+		Proposals::<Test>::insert(
+			0,
+			AssetSpend {
+				proposer: ALICE,
+				beneficiary: BOB,
+				asset_id: AssetId::POLKADEX,
+				value: 400,
+				bond: 10,
+			},
+		);
+		assert_noop!(
+			AssetTreasury::approve_proposal(Origin::root(), 0),
+			Error::<Test>::NativeAsset
+		);
+	});
+}
+
+#[test]
+fn approve_proposal_keeps_unpayable_proposals() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetTreasury::propose_spend(Origin::signed(ALICE), ASSET, 1_200, BOB));
+		assert!(AssetTreasury::approve_proposal(Origin::root(), 0).is_err());
+		assert!(AssetTreasury::proposals(0).is_some());
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert_eq!(Tokens::free_balance(ASSET, &TREASURY), 1_000);
+	});
+}
+
+#[test]
+fn reject_proposal_slashes_bond() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetTreasury::propose_spend(Origin::signed(ALICE), ASSET, 400, BOB));
+		assert_noop!(
+			AssetTreasury::reject_proposal(Origin::signed(ALICE), 0),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(AssetTreasury::reject_proposal(Origin::root(), 0));
+		assert_eq!(AssetTreasury::proposals(0), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 90);
+		assert_eq!(last_event(), Event::AssetTreasury(crate::Event::Rejected(0, 10)));
+		assert_noop!(
+			AssetTreasury::reject_proposal(Origin::root(), 0),
+			Error::<Test>::InvalidIndex
+		);
+	});
+}
+
+#[test]
+fn burn_destroys_treasury_assets() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetTreasury::burn(Origin::signed(ALICE), ASSET, 100),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetTreasury::burn(Origin::root(), AssetId::POLKADEX, 1),
+			Error::<Test>::NativeAsset
+		);
+		assert_noop!(AssetTreasury::burn(Origin::root(), ASSET, 0), Error::<Test>::ZeroAmount);
+		assert_noop!(
+			AssetTreasury::burn(Origin::root(), ASSET, 1_001),
+			Error::<Test>::InsufficientTreasuryBalance
+		);
+
+		assert_ok!(AssetTreasury::burn(Origin::root(), ASSET, 1_000));
+		assert_eq!(Tokens::free_balance(ASSET, &TREASURY), 0);
+		assert_eq!(Tokens::total_issuance(ASSET), 0);
+		assert_eq!(last_event(), Event::AssetTreasury(crate::Event::Burned(ASSET, 1_000)));
+	});
+}
+
+#[test]
+fn accepted_offer_converts_assets_into_native() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetTreasury::offer_conversion(Origin::signed(ALICE), AssetId::POLKADEX, 300, 50),
+			Error::<Test>::NativeAsset
+		);
+		assert_noop!(
+			AssetTreasury::offer_conversion(Origin::signed(ALICE), ASSET, 300, 0),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			AssetTreasury::offer_conversion(Origin::signed(BOB), ASSET, 300, 50),
+			Error::<Test>::InsufficientBuyersBalance
+		);
+
+		assert_ok!(AssetTreasury::offer_conversion(Origin::signed(ALICE), ASSET, 300, 50));
+		assert_eq!(Balances::reserved_balance(ALICE), 50);
+		assert_eq!(
+			AssetTreasury::offers(0),
+			Some(ConversionOffer { buyer: ALICE, asset_id: ASSET, amount: 300, price: 50 })
+		);
+		assert_eq!(last_event(), Event::AssetTreasury(crate::Event::ConversionOffered(0, ALICE)));
+
+		assert_noop!(
+			AssetTreasury::accept_offer(Origin::signed(ALICE), 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(AssetTreasury::accept_offer(Origin::root(), 0));
+		assert_eq!(AssetTreasury::offers(0), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 50);
+		assert_eq!(Balances::free_balance(TREASURY), 51);
+		assert_eq!(Tokens::free_balance(ASSET, &ALICE), 300);
+		assert_eq!(Tokens::free_balance(ASSET, &TREASURY), 700);
+		assert_eq!(last_event(), Event::AssetTreasury(crate::Event::Converted(0, ASSET, 300, 50)));
+	});
+}
+
+#[test]
+fn offers_exceeding_treasury_holdings_stay_pending() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetTreasury::offer_conversion(Origin::signed(ALICE), ASSET, 1_001, 50));
+		assert_noop!(
+			AssetTreasury::accept_offer(Origin::root(), 0),
+			Error::<Test>::InsufficientTreasuryBalance
+		);
+		assert!(AssetTreasury::offers(0).is_some());
+		assert_eq!(Balances::reserved_balance(ALICE), 50);
+	});
+}
+
+#[test]
+fn buyers_cancel_their_offers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetTreasury::offer_conversion(Origin::signed(ALICE), ASSET, 300, 50));
+		assert_noop!(AssetTreasury::cancel_offer(Origin::signed(BOB), 0), Error::<Test>::NotBuyer);
+		assert_noop!(
+			AssetTreasury::cancel_offer(Origin::signed(ALICE), 1),
+			Error::<Test>::InvalidIndex
+		);
+
+		assert_ok!(AssetTreasury::cancel_offer(Origin::signed(ALICE), 0));
+		assert_eq!(AssetTreasury::offers(0), None);
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		assert_eq!(last_event(), Event::AssetTreasury(crate::Event::OfferCancelled(0)));
+		assert_noop!(AssetTreasury::accept_offer(Origin::root(), 0), Error::<Test>::InvalidIndex);
+	});
+}
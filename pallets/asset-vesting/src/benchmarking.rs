@@ -0,0 +1,88 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for asset-vesting-pallet
+
+use super::*;
+use crate::Pallet as AssetVesting;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::StaticLookup;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+const ASSET: AssetId = AssetId::Asset(1);
+
+/// Returns a schedule paying `MinVestedTransfer` over two periods starting at block zero.
+fn schedule<T: Config>() -> VestingScheduleOf<T> {
+	VestingSchedule {
+		start: 0u32.into(),
+		period: 2u32.into(),
+		period_count: 2,
+		per_period: T::MinVestedTransfer::get(),
+	}
+}
+
+/// Creates `ASSET` and credits `amount` of it to `who`.
+fn fund<T: Config>(who: &T::AccountId, amount: BalanceOf<T>) {
+	T::BenchmarkHelper::create_asset(ASSET);
+	T::Currency::deposit(ASSET, who, amount).expect("benchmark balances can be set; qed");
+}
+
+benchmarks! {
+	claim {
+		let i in 1 .. T::MaxVestingSchedules::get();
+		let who: T::AccountId = whitelisted_caller();
+		let schedules = vec![schedule::<T>(); i as usize];
+		fund::<T>(&who, T::MinVestedTransfer::get() * (2 * i).into());
+		AssetVesting::<T>::update_vesting_schedules(
+			RawOrigin::Root.into(),
+			T::Lookup::unlookup(who.clone()),
+			ASSET,
+			schedules,
+		)?;
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+	}: _(RawOrigin::Signed(who.clone()), ASSET)
+	verify {
+		assert_eq!(AssetVesting::<T>::vesting_schedules(&who, ASSET).len(), i as usize);
+	}
+
+	vested_transfer {
+		let origin = T::VestedTransferOrigin::successful_origin();
+		let from = T::VestedTransferOrigin::ensure_origin(origin.clone())
+			.expect("successful origin resolves to an account; qed");
+		fund::<T>(&from, T::MinVestedTransfer::get() * 10u32.into());
+		let to: T::AccountId = account("to", 0, SEED);
+	}: _<T::Origin>(origin, T::Lookup::unlookup(to.clone()), ASSET, schedule::<T>())
+	verify {
+		assert_eq!(AssetVesting::<T>::locked_balance(&to, ASSET), T::MinVestedTransfer::get() * 2u32.into());
+	}
+
+	update_vesting_schedules {
+		let i in 1 .. T::MaxVestingSchedules::get();
+		let who: T::AccountId = account("who", 0, SEED);
+		fund::<T>(&who, T::MinVestedTransfer::get() * (2 * i).into());
+		let schedules = vec![schedule::<T>(); i as usize];
+	}: _(RawOrigin::Root, T::Lookup::unlookup(who.clone()), ASSET, schedules)
+	verify {
+		assert_eq!(AssetVesting::<T>::vesting_schedules(&who, ASSET).len(), i as usize);
+	}
+}
+
+impl_benchmark_test_suite!(AssetVesting, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,339 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+//! # Asset Vesting Pallet
+//!
+//! Vests `AssetId::Asset` tokens the way `orml_vesting` vests native PDEX. Each account has a
+//! list of `VestingSchedule`s per asset and the amount still vesting is locked in the asset with
+//! `MultiLockableCurrency`. Schedules are created by `VestedTransferOrigin`, by root or in
+//! genesis, and vested amounts are unlocked with `claim`.
+
+pub use orml_vesting::VestingSchedule;
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use frame_support::traits::LockIdentifier;
+use orml_traits::MultiCurrency;
+use polkadex_primitives::assets::AssetId;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+/// Identifier of the lock on vesting assets.
+pub const ASSET_VESTING_ID: LockIdentifier = *b"assetvst";
+
+/// Balance type of the vested assets.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
+/// Vesting schedule of an asset.
+pub type VestingScheduleOf<T> =
+	VestingSchedule<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
+
+/// Prepares the state the benchmarks of this pallet run in.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Makes `asset_id` usable through the pallet's currency.
+	fn create_asset(asset_id: AssetId);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper for () {
+	fn create_asset(_asset_id: AssetId) {}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, transactional};
+	use frame_system::pallet_prelude::*;
+	use orml_traits::MultiLockableCurrency;
+	use sp_runtime::traits::{BlockNumberProvider, CheckedAdd, Saturating, StaticLookup, Zero};
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Module that holds and locks the vested assets
+		type Currency: MultiLockableCurrency<Self::AccountId, CurrencyId = AssetId>;
+		/// Minimum amount of a vested transfer
+		#[pallet::constant]
+		type MinVestedTransfer: Get<BalanceOf<Self>>;
+		/// Origin that may create vested transfers, resolving to the account paying them
+		type VestedTransferOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+		/// Maximum number of vesting schedules of an account in one asset
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+		/// Block number vesting schedules are measured against
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// Creates the asset used by the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+	}
+
+	/// Vesting schedules of an account in an asset
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_schedules)]
+	pub type VestingSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		AssetId,
+		Vec<VestingScheduleOf<T>>,
+		ValueQuery,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Vesting schedules as account, asset, start, period, period count and amount per
+		/// period
+		pub vesting:
+			Vec<(T::AccountId, AssetId, T::BlockNumber, T::BlockNumber, u32, BalanceOf<T>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { vesting: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (who, asset_id, start, period, period_count, per_period) in &self.vesting {
+				assert!(*asset_id != AssetId::POLKADEX, "Native PDEX vests in orml_vesting");
+				let schedule = VestingSchedule {
+					start: *start,
+					period: *period,
+					period_count: *period_count,
+					per_period: *per_period,
+				};
+				let amount = Pallet::<T>::ensure_valid_vesting_schedule(&schedule)
+					.expect("Invalid vesting schedule in genesis");
+				let total = Pallet::<T>::locked_balance(who, *asset_id)
+					.checked_add(&amount)
+					.expect("Vesting amounts overflow in genesis");
+				assert!(
+					T::Currency::free_balance(*asset_id, who) >= total,
+					"Account does not hold the vested assets in genesis"
+				);
+				VestingSchedules::<T>::try_mutate(who, asset_id, |schedules| {
+					ensure!(
+						(schedules.len() as u32) < T::MaxVestingSchedules::get(),
+						Error::<T>::TooManyVestingSchedules
+					);
+					schedules.push(schedule);
+					Ok::<_, Error<T>>(())
+				})
+				.expect("Too many vesting schedules in genesis");
+				T::Currency::set_lock(ASSET_VESTING_ID, *asset_id, who, total)
+					.expect("Vested assets can be locked in genesis");
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		BalanceOf<T> = "Balance",
+		VestingScheduleOf<T> = "VestingScheduleOf"
+	)]
+	pub enum Event<T: Config> {
+		/// A vesting schedule was added. \[from, to, asset_id, schedule\]
+		VestingScheduleAdded(T::AccountId, T::AccountId, AssetId, VestingScheduleOf<T>),
+		/// Vested assets were unlocked. \[who, asset_id, still_locked\]
+		Claimed(T::AccountId, AssetId, BalanceOf<T>),
+		/// The vesting schedules of an account were replaced. \[who, asset_id\]
+		VestingSchedulesUpdated(T::AccountId, AssetId),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Native PDEX vests in `orml_vesting`.
+		NativeAsset,
+		/// The vesting period is zero.
+		ZeroVestingPeriod,
+		/// The number of vesting periods is zero.
+		ZeroVestingPeriodCount,
+		/// The account does not hold the assets to lock.
+		InsufficientBalanceToLock,
+		/// The account has `MaxVestingSchedules` schedules in the asset.
+		TooManyVestingSchedules,
+		/// The vested amount is below `MinVestedTransfer`.
+		AmountLow,
+		/// The end or the amount of the schedule overflows.
+		NumOverflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Unlock the vested part of the sender's `asset_id`.
+		#[pallet::weight(T::WeightInfo::claim(T::MaxVestingSchedules::get()))]
+		pub fn claim(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let locked = Self::do_claim(&who, asset_id);
+			Self::deposit_event(Event::Claimed(who, asset_id, locked));
+			Ok(())
+		}
+
+		/// Transfer the total amount of `schedule` in `asset_id` to `dest` and lock it until it
+		/// vests.
+		#[pallet::weight(T::WeightInfo::vested_transfer())]
+		#[transactional]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			asset_id: AssetId,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			let from = T::VestedTransferOrigin::ensure_origin(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			ensure!(asset_id != AssetId::POLKADEX, Error::<T>::NativeAsset);
+			let amount = Self::ensure_valid_vesting_schedule(&schedule)?;
+			ensure!(amount >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+			let total = Self::prune_vesting_schedules(&to, asset_id)
+				.checked_add(&amount)
+				.ok_or(Error::<T>::NumOverflow)?;
+
+			VestingSchedules::<T>::try_mutate(&to, asset_id, |schedules| -> DispatchResult {
+				ensure!(
+					(schedules.len() as u32) < T::MaxVestingSchedules::get(),
+					Error::<T>::TooManyVestingSchedules
+				);
+				schedules.push(schedule.clone());
+				Ok(())
+			})?;
+			T::Currency::transfer(asset_id, &from, &to, amount)?;
+			T::Currency::set_lock(ASSET_VESTING_ID, asset_id, &to, total)?;
+			Self::deposit_event(Event::VestingScheduleAdded(from, to, asset_id, schedule));
+			Ok(())
+		}
+
+		/// Replace the vesting schedules of `who` in `asset_id`.
+		#[pallet::weight(T::WeightInfo::update_vesting_schedules(vesting_schedules.len() as u32))]
+		pub fn update_vesting_schedules(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			asset_id: AssetId,
+			vesting_schedules: Vec<VestingScheduleOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(asset_id != AssetId::POLKADEX, Error::<T>::NativeAsset);
+			ensure!(
+				vesting_schedules.len() as u32 <= T::MaxVestingSchedules::get(),
+				Error::<T>::TooManyVestingSchedules
+			);
+			let mut total = BalanceOf::<T>::zero();
+			for schedule in &vesting_schedules {
+				let amount = Self::ensure_valid_vesting_schedule(schedule)?;
+				total = total.checked_add(&amount).ok_or(Error::<T>::NumOverflow)?;
+			}
+			ensure!(
+				T::Currency::free_balance(asset_id, &who) >= total,
+				Error::<T>::InsufficientBalanceToLock
+			);
+
+			if vesting_schedules.is_empty() {
+				VestingSchedules::<T>::remove(&who, asset_id);
+				T::Currency::remove_lock(ASSET_VESTING_ID, asset_id, &who)?;
+			} else {
+				VestingSchedules::<T>::insert(&who, asset_id, vesting_schedules);
+				T::Currency::set_lock(ASSET_VESTING_ID, asset_id, &who, total)?;
+			}
+			Self::deposit_event(Event::VestingSchedulesUpdated(who, asset_id));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Updates the lock of `who` in `asset_id` to the amount still vesting and returns it.
+		fn do_claim(who: &T::AccountId, asset_id: AssetId) -> BalanceOf<T> {
+			let locked = Self::prune_vesting_schedules(who, asset_id);
+			if locked.is_zero() {
+				// the lock may already be gone
+				let _ = T::Currency::remove_lock(ASSET_VESTING_ID, asset_id, who);
+			} else {
+				// reducing an existing lock does not fail
+				let _ = T::Currency::set_lock(ASSET_VESTING_ID, asset_id, who, locked);
+			}
+			locked
+		}
+
+		/// Returns the amount of `asset_id` still vesting for `who`.
+		pub fn locked_balance(who: &T::AccountId, asset_id: AssetId) -> BalanceOf<T> {
+			let now = T::BlockNumberProvider::current_block_number();
+			VestingSchedules::<T>::get(who, asset_id)
+				.iter()
+				.fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+					total.saturating_add(schedule.locked_amount(now))
+				})
+		}
+
+		/// Removes the finished schedules of `who` in `asset_id` and returns the amount still
+		/// vesting.
+		fn prune_vesting_schedules(who: &T::AccountId, asset_id: AssetId) -> BalanceOf<T> {
+			let now = T::BlockNumberProvider::current_block_number();
+			VestingSchedules::<T>::mutate_exists(who, asset_id, |maybe_schedules| {
+				let mut total = BalanceOf::<T>::zero();
+				if let Some(schedules) = maybe_schedules.as_mut() {
+					schedules.retain(|schedule| {
+						let amount = schedule.locked_amount(now);
+						total = total.saturating_add(amount);
+						!amount.is_zero()
+					});
+					if schedules.is_empty() {
+						*maybe_schedules = None;
+					}
+				}
+				total
+			})
+		}
+
+		/// Returns the total amount of a valid `schedule`.
+		fn ensure_valid_vesting_schedule(
+			schedule: &VestingScheduleOf<T>,
+		) -> Result<BalanceOf<T>, Error<T>> {
+			ensure!(!schedule.period.is_zero(), Error::<T>::ZeroVestingPeriod);
+			ensure!(!schedule.period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+			ensure!(schedule.end().is_some(), Error::<T>::NumOverflow);
+			schedule.total_amount().ok_or(Error::<T>::NumOverflow)
+		}
+	}
+}
@@ -0,0 +1,187 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Error, VestingSchedule};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use polkadex_primitives::assets::AssetId;
+use sp_runtime::DispatchError;
+
+fn frozen(who: AccountId) -> Balance {
+	Tokens::accounts(who, ASSET).frozen
+}
+
+fn last_event() -> Event {
+	System::events().pop().expect("an event was deposited").event
+}
+
+/// Vests 20 over two periods of 10 blocks starting at block 1.
+fn schedule() -> VestingSchedule<u64, Balance> {
+	VestingSchedule { start: 1, period: 10, period_count: 2, per_period: 10 }
+}
+
+#[test]
+fn genesis_schedules_lock_assets() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(AssetVesting::vesting_schedules(CHARLIE, ASSET).len(), 1);
+		assert_eq!(frozen(CHARLIE), 30);
+		assert!(Tokens::transfer(ASSET, &CHARLIE, &BOB, 21).is_err());
+		assert_ok!(Tokens::transfer(ASSET, &CHARLIE, &BOB, 20));
+	});
+}
+
+#[test]
+fn vested_transfer_locks_transferred_assets() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetVesting::vested_transfer(Origin::signed(ALICE), BOB, ASSET, schedule()));
+		assert_eq!(Tokens::free_balance(ASSET, &ALICE), 80);
+		assert_eq!(Tokens::free_balance(ASSET, &BOB), 20);
+		assert_eq!(frozen(BOB), 20);
+		assert_eq!(AssetVesting::vesting_schedules(BOB, ASSET), vec![schedule()]);
+		assert_eq!(
+			last_event(),
+			Event::AssetVesting(crate::Event::VestingScheduleAdded(ALICE, BOB, ASSET, schedule()))
+		);
+
+		assert_ok!(AssetVesting::vested_transfer(Origin::signed(ALICE), BOB, ASSET, schedule()));
+		assert_eq!(frozen(BOB), 40);
+		assert_noop!(
+			AssetVesting::vested_transfer(Origin::signed(ALICE), BOB, ASSET, schedule()),
+			Error::<Test>::TooManyVestingSchedules
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_rejects_invalid_schedules() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetVesting::vested_transfer(Origin::signed(BOB), CHARLIE, ASSET, schedule()),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				AssetId::POLKADEX,
+				schedule()
+			),
+			Error::<Test>::NativeAsset
+		);
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				ASSET,
+				VestingSchedule { period: 0, ..schedule() }
+			),
+			Error::<Test>::ZeroVestingPeriod
+		);
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				ASSET,
+				VestingSchedule { period_count: 0, ..schedule() }
+			),
+			Error::<Test>::ZeroVestingPeriodCount
+		);
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				ASSET,
+				VestingSchedule { per_period: 2, ..schedule() }
+			),
+			Error::<Test>::AmountLow
+		);
+		assert!(AssetVesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			ASSET,
+			VestingSchedule { per_period: 60, ..schedule() }
+		)
+		.is_err());
+		assert_eq!(Tokens::free_balance(ASSET, &BOB), 0);
+	});
+}
+
+#[test]
+fn claim_unlocks_vested_assets() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetVesting::vested_transfer(Origin::signed(ALICE), BOB, ASSET, schedule()));
+
+		System::set_block_number(11);
+		assert_ok!(AssetVesting::claim(Origin::signed(BOB), ASSET));
+		assert_eq!(frozen(BOB), 10);
+		assert_eq!(last_event(), Event::AssetVesting(crate::Event::Claimed(BOB, ASSET, 10)));
+
+		System::set_block_number(21);
+		// reading the locked balance leaves finished schedules to `claim`
+		assert_eq!(AssetVesting::locked_balance(&BOB, ASSET), 0);
+		assert_eq!(AssetVesting::vesting_schedules(BOB, ASSET), vec![schedule()]);
+		assert_ok!(AssetVesting::claim(Origin::signed(BOB), ASSET));
+		assert_eq!(frozen(BOB), 0);
+		assert!(AssetVesting::vesting_schedules(BOB, ASSET).is_empty());
+		assert_ok!(Tokens::transfer(ASSET, &BOB, &ALICE, 20));
+	});
+}
+
+#[test]
+fn root_updates_vesting_schedules() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetVesting::update_vesting_schedules(Origin::signed(ALICE), CHARLIE, ASSET, vec![]),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			AssetVesting::update_vesting_schedules(
+				Origin::root(),
+				CHARLIE,
+				ASSET,
+				vec![VestingSchedule { per_period: 30, ..schedule() }]
+			),
+			Error::<Test>::InsufficientBalanceToLock
+		);
+		assert_noop!(
+			AssetVesting::update_vesting_schedules(
+				Origin::root(),
+				CHARLIE,
+				ASSET,
+				vec![schedule(); 3]
+			),
+			Error::<Test>::TooManyVestingSchedules
+		);
+
+		assert_ok!(AssetVesting::update_vesting_schedules(
+			Origin::root(),
+			CHARLIE,
+			ASSET,
+			vec![schedule()]
+		));
+		assert_eq!(frozen(CHARLIE), 20);
+		assert_eq!(
+			last_event(),
+			Event::AssetVesting(crate::Event::VestingSchedulesUpdated(CHARLIE, ASSET))
+		);
+
+		assert_ok!(AssetVesting::update_vesting_schedules(Origin::root(), CHARLIE, ASSET, vec![]));
+		assert_eq!(frozen(CHARLIE), 0);
+		assert!(AssetVesting::vesting_schedules(CHARLIE, ASSET).is_empty());
+	});
+}
@@ -0,0 +1,283 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for erc20-pdex-migration-pallet
+
+use super::*;
+use crate::{ethereum::fixtures, Pallet as PDEXMigration};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{EnsureOrigin, Get, Hooks};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrency;
+use polkadex_primitives::assets::AssetId;
+use sp_runtime::SaturatedConversion;
+
+const SEED: u32 = 0;
+
+/// Returns the benchmark relayer origin together with the address it resolves to.
+fn relayer_origin<T: Config>() -> (T::Origin, H160) {
+	let origin = T::CallOrigin::successful_origin();
+	let relayer = T::CallOrigin::ensure_origin(origin.clone())
+		.expect("successful origin resolves to a relayer; qed");
+	(origin, relayer)
+}
+
+/// Registers `count` relayers besides the origin relayer and returns them.
+fn other_relayers<T: Config>(relayer: H160, count: u32) -> Vec<H160> {
+	let others: Vec<H160> =
+		(0..count).map(|i| H160::from_low_u64_be(u64::MAX - i as u64)).collect();
+	let mut relayers = others.clone();
+	relayers.push(relayer);
+	Relayers::<T>::put(relayers);
+	Threshold::<T>::put(count + 1);
+	others
+}
+
+fn attestation<T: Config>(recipient: T::AccountId) -> Attestation<T::AccountId> {
+	Attestation {
+		token: H160::repeat_byte(1),
+		sender: H160::repeat_byte(2),
+		recipient,
+		amount: PDEXMigration::<T>::to_erc20_amount(1_000_000_000_000u128.saturated_into()),
+	}
+}
+
+benchmarks! {
+	mint {
+		let r in 1 .. T::MaxRelayers::get();
+		let p in 256 .. 4_096;
+		let (origin, relayer) = relayer_origin::<T>();
+		let others = other_relayers::<T>(relayer, r - 1);
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let attestation = attestation::<T>(recipient.clone());
+		let contract = H160::repeat_byte(4);
+		Address::<T>::put(contract);
+		RegisteredTokens::<T>::insert(
+			attestation.token,
+			TokenInfo { asset_id: AssetId::POLKADEX, decimals: ERC20_DECIMALS as u8 },
+		);
+
+		let receipt = fixtures::padded_locked_receipt(
+			contract,
+			attestation.token,
+			attestation.sender,
+			&recipient.encode(),
+			attestation.amount,
+			p as usize,
+		);
+		let (receipts_root, proof) = fixtures::single_receipt_proof(0, &receipt);
+		let block_hash = H256::repeat_byte(3);
+		T::EthereumHeaders::note_receipts_root(block_hash, receipts_root);
+		let deposit_id = PDEXMigration::<T>::deposit_id(block_hash, 0);
+		if !others.is_empty() {
+			Proposals::<T>::insert(deposit_id, 0, MintProposal {
+				votes: others.into_iter().map(|other| (other, attestation.clone())).collect(),
+				expiry: T::ProposalLifetime::get(),
+			});
+		}
+	}: _<T::Origin>(origin, block_hash, 0, proof, 0)
+	verify {
+		assert!(PDEXMigration::<T>::is_deposit_processed(deposit_id, 0));
+	}
+
+	submit_receipts_root {
+		let r in 1 .. T::MaxRelayers::get();
+		let (origin, relayer) = relayer_origin::<T>();
+		let others = other_relayers::<T>(relayer, r - 1);
+		let block_hash = H256::repeat_byte(1);
+		let receipts_root = H256::repeat_byte(2);
+		ReceiptsRootVotes::<T>::insert(
+			block_hash,
+			others.into_iter().map(|other| (other, receipts_root)).collect::<Vec<_>>(),
+		);
+	}: _<T::Origin>(origin, block_hash, receipts_root)
+	verify {
+		assert_eq!(PDEXMigration::<T>::receipts_roots(block_hash), Some(receipts_root));
+	}
+
+	register_token {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let token = H160::repeat_byte(1);
+	}: _<T::Origin>(origin, token, AssetId::POLKADEX, 6)
+	verify {
+		assert!(PDEXMigration::<T>::registered_tokens(token).is_some());
+	}
+
+	deregister_token {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let token = H160::repeat_byte(1);
+		RegisteredTokens::<T>::insert(token, TokenInfo { asset_id: AssetId::Asset(1), decimals: 6 });
+	}: _<T::Origin>(origin, token)
+	verify {
+		assert!(PDEXMigration::<T>::registered_tokens(token).is_none());
+	}
+
+	add_relayer {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let account: T::AccountId = account("relayer", 0, SEED);
+		let relayer = H160::repeat_byte(1);
+	}: _<T::Origin>(origin, account.clone(), relayer)
+	verify {
+		assert!(PDEXMigration::<T>::relayers().contains(&relayer));
+		assert_eq!(PDEXMigration::<T>::relayer_accounts(account), Some(relayer));
+	}
+
+	remove_relayer {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let account: T::AccountId = account("relayer", 0, SEED);
+		let relayer = H160::repeat_byte(1);
+		Relayers::<T>::put(vec![H160::repeat_byte(2), relayer]);
+		RelayerAccounts::<T>::insert(&account, relayer);
+		Threshold::<T>::put(1);
+	}: _<T::Origin>(origin, account.clone())
+	verify {
+		assert!(!PDEXMigration::<T>::relayers().contains(&relayer));
+		assert!(PDEXMigration::<T>::relayer_accounts(account).is_none());
+	}
+
+	set_threshold {
+		let origin = T::GovernanceOrigin::successful_origin();
+		Relayers::<T>::put(vec![H160::repeat_byte(1), H160::repeat_byte(2)]);
+	}: _<T::Origin>(origin, 2)
+	verify {
+		assert_eq!(PDEXMigration::<T>::threshold(), 2);
+	}
+
+	set_migration_cap {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let cap: T::Balance = 1_000u32.into();
+	}: _<T::Origin>(origin, AssetId::POLKADEX, Some(cap))
+	verify {
+		assert_eq!(PDEXMigration::<T>::migration_cap(AssetId::POLKADEX), Some(cap));
+	}
+
+	set_mint_period_limit {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let limit = PeriodLimit { period: 10u32.into(), limit: 1_000u32.into() };
+	}: _<T::Origin>(origin, AssetId::POLKADEX, Some(limit))
+	verify {
+		assert_eq!(PDEXMigration::<T>::mint_period_limit(AssetId::POLKADEX), Some(limit));
+	}
+
+	set_recipient_limit {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let limit: T::Balance = 1_000u32.into();
+	}: _<T::Origin>(origin, AssetId::POLKADEX, Some(limit))
+	verify {
+		assert_eq!(PDEXMigration::<T>::recipient_limit(AssetId::POLKADEX), Some(limit));
+	}
+
+	burn_for_erc20 {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount: T::Balance = 1_000_000_000_000u128.saturated_into();
+		T::Currency::deposit(AssetId::POLKADEX, &caller, amount + amount)?;
+	}: _(RawOrigin::Signed(caller), amount, H160::repeat_byte(1))
+	verify {
+		assert!(PDEXMigration::<T>::withdrawals(0).is_some());
+	}
+
+	confirm_withdrawal {
+		let r in 1 .. T::MaxRelayers::get();
+		let (origin, relayer) = relayer_origin::<T>();
+		let others = other_relayers::<T>(relayer, r - 1);
+		let eth_tx_hash = H256::repeat_byte(1);
+		Withdrawals::<T>::insert(0, Withdrawal {
+			who: account("who", 0, SEED),
+			eth_recipient: H160::repeat_byte(2),
+			amount: 1_000u32.into(),
+			erc20_amount: U256::from(1_000u32),
+			requested_at: 0u32.into(),
+			confirmations: others.into_iter().map(|other| (other, eth_tx_hash)).collect(),
+			status: WithdrawalStatus::Pending,
+		});
+	}: _<T::Origin>(origin, 0, eth_tx_hash)
+	verify {
+		assert_eq!(
+			PDEXMigration::<T>::withdrawals(0).map(|w| w.status),
+			Some(WithdrawalStatus::Released(eth_tx_hash))
+		);
+	}
+
+	set_mint_delay {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let threshold: T::Balance = 1_000u32.into();
+	}: _<T::Origin>(origin, AssetId::POLKADEX, Some(threshold), 10u32.into())
+	verify {
+		assert_eq!(PDEXMigration::<T>::delay_threshold(AssetId::POLKADEX), Some(threshold));
+	}
+
+	cancel_mint {
+		let origin = T::GuardianOrigin::successful_origin();
+		let deposit_id = H256::repeat_byte(1);
+		QueuedMints::<T>::insert(deposit_id, 0, QueuedMint {
+			attestation: attestation::<T>(account("recipient", 0, SEED)),
+			asset_id: AssetId::POLKADEX,
+			balance: 1_000u32.into(),
+			execute_at: 10u32.into(),
+		});
+	}: _<T::Origin>(origin, deposit_id, 0)
+	verify {
+		assert!(PDEXMigration::<T>::queued_mints(deposit_id, 0).is_none());
+	}
+
+	pause {
+		let origin = T::GuardianOrigin::successful_origin();
+	}: _<T::Origin>(origin)
+	verify {
+		assert!(PDEXMigration::<T>::paused());
+	}
+
+	unpause {
+		let origin = T::GuardianOrigin::successful_origin();
+		Paused::<T>::put(true);
+	}: _<T::Origin>(origin)
+	verify {
+		assert!(!PDEXMigration::<T>::paused());
+	}
+
+	on_initialize {
+		let p in 0 .. 100;
+		let q in 0 .. 100;
+		let now: T::BlockNumber = 10u32.into();
+		for i in 0 .. p {
+			let deposit_id = H256::from_low_u64_be(i as u64);
+			Proposals::<T>::insert(deposit_id, 0, MintProposal {
+				votes: vec![(H160::repeat_byte(1), attestation::<T>(account("recipient", i, SEED)))],
+				expiry: now,
+			});
+			ProposalExpiries::<T>::append(now, (deposit_id, 0));
+		}
+		for i in 0 .. q {
+			let deposit_id = H256::from_low_u64_be(i as u64);
+			QueuedMints::<T>::insert(deposit_id, 1, QueuedMint {
+				attestation: attestation::<T>(account("recipient", i, SEED)),
+				asset_id: AssetId::POLKADEX,
+				balance: 1_000_000_000_000u128.saturated_into(),
+				execute_at: now,
+			});
+			MintQueue::<T>::append(now, (deposit_id, 1));
+		}
+	}: {
+		PDEXMigration::<T>::on_initialize(now);
+	}
+	verify {
+		assert_eq!(MintQueue::<T>::get(now).len(), 0);
+	}
+}
+
+impl_benchmark_test_suite!(PDEXMigration, crate::mock::new_test_ext(), crate::mock::Test);
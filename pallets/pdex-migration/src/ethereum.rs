@@ -0,0 +1,276 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Verification of Ethereum transaction receipts against a known receipts root.
+
+use codec::Decode;
+use rlp::Rlp;
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Event signature of `Locked(address indexed token, address indexed sender,
+/// bytes32 indexed recipient, uint256 amount)` emitted by the migration contract.
+pub const LOCKED_EVENT: &[u8] = b"Locked(address,address,bytes32,uint256)";
+
+/// Source of the receipts roots of Ethereum blocks the chain considers final.
+///
+/// Implemented by light-client pallets or trusted oracles.
+pub trait EthereumHeaders {
+	/// Returns the receipts root of the Ethereum block `block_hash` if the block is known.
+	fn receipts_root(block_hash: H256) -> Option<H256>;
+
+	/// Makes `receipts_root` known for `block_hash`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn note_receipts_root(block_hash: H256, receipts_root: H256);
+}
+
+/// A log entry of an Ethereum transaction receipt.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct Log {
+	/// Contract that emitted the log
+	pub address: H160,
+	/// Indexed event parameters, the first being the event signature
+	pub topics: Vec<H256>,
+	/// Non-indexed event parameters
+	pub data: Vec<u8>,
+}
+
+impl Log {
+	/// Decodes a `Locked` event into token, sender, recipient and amount.
+	pub fn decode_locked<AccountId: Decode>(&self) -> Option<(H160, H160, AccountId, U256)> {
+		if self.topics.len() != 4 ||
+			self.topics[0] != H256(keccak_256(LOCKED_EVENT)) ||
+			self.data.len() != 32
+		{
+			return None
+		}
+		let token = H160::from_slice(&self.topics[1].as_bytes()[12..]);
+		let sender = H160::from_slice(&self.topics[2].as_bytes()[12..]);
+		let recipient = AccountId::decode(&mut self.topics[3].as_bytes()).ok()?;
+		let amount = U256::from_big_endian(&self.data);
+		Some((token, sender, recipient, amount))
+	}
+}
+
+/// Reference to a trie node, either by hash or embedded in its parent.
+enum NodeRef<'a> {
+	Hash(H256),
+	Inline(&'a [u8]),
+}
+
+fn node_ref<'a>(item: &Rlp<'a>) -> Option<NodeRef<'a>> {
+	if item.is_list() {
+		Some(NodeRef::Inline(item.as_raw()))
+	} else {
+		let data = item.data().ok()?;
+		if data.len() == 32 {
+			Some(NodeRef::Hash(H256::from_slice(data)))
+		} else {
+			None
+		}
+	}
+}
+
+fn to_nibbles(key: &[u8]) -> Vec<u8> {
+	key.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]).collect()
+}
+
+/// Decodes a hex-prefix encoded path into its nibbles and whether it terminates in a leaf.
+fn decode_path(encoded: &[u8]) -> Option<(Vec<u8>, bool)> {
+	let first = *encoded.first()?;
+	let is_leaf = match first >> 4 {
+		0 | 1 => false,
+		2 | 3 => true,
+		_ => return None,
+	};
+	let mut nibbles = Vec::with_capacity(encoded.len() * 2);
+	if first & 0x10 != 0 {
+		nibbles.push(first & 0x0f);
+	}
+	nibbles.extend(to_nibbles(&encoded[1..]));
+	Some((nibbles, is_leaf))
+}
+
+/// Verifies that `proof` proves the inclusion of the receipt with index `receipt_index` in the
+/// receipts trie with root `receipts_root` and returns the encoded receipt.
+///
+/// `proof` holds the trie nodes on the path from the root to the receipt, in that order.
+pub fn verify_receipt_proof(
+	receipts_root: H256,
+	receipt_index: u64,
+	proof: &[Vec<u8>],
+) -> Option<Vec<u8>> {
+	let key = to_nibbles(&rlp::encode(&receipt_index));
+	let mut proof = proof.iter();
+	let mut expected = NodeRef::Hash(receipts_root);
+	let mut position = 0;
+
+	loop {
+		let node = match expected {
+			NodeRef::Hash(hash) => {
+				let node = proof.next()?;
+				if H256(keccak_256(node)) != hash {
+					return None
+				}
+				node.as_slice()
+			},
+			NodeRef::Inline(node) => node,
+		};
+		let node = Rlp::new(node);
+		match node.item_count().ok()? {
+			17 => {
+				if position == key.len() {
+					let value = node.at(16).ok()?.data().ok()?;
+					return if value.is_empty() { None } else { Some(value.to_vec()) }
+				}
+				expected = node_ref(&node.at(key[position] as usize).ok()?)?;
+				position += 1;
+			},
+			2 => {
+				let (path, is_leaf) = decode_path(node.at(0).ok()?.data().ok()?)?;
+				let remaining = &key[position..];
+				if is_leaf {
+					return if remaining == path.as_slice() {
+						Some(node.at(1).ok()?.data().ok()?.to_vec())
+					} else {
+						None
+					}
+				}
+				if !remaining.starts_with(&path) {
+					return None
+				}
+				position += path.len();
+				expected = node_ref(&node.at(1).ok()?)?;
+			},
+			_ => return None,
+		}
+	}
+}
+
+/// Returns the log with index `log_index` of an encoded receipt.
+///
+/// Both legacy receipts and typed (EIP-2718) receipts are supported.
+pub fn receipt_log(receipt: &[u8], log_index: u64) -> Option<Log> {
+	let payload = match receipt.first() {
+		Some(kind) if *kind < 0x80 => &receipt[1..],
+		_ => receipt,
+	};
+	let receipt = Rlp::new(payload);
+	if receipt.item_count().ok()? != 4 {
+		return None
+	}
+	let log = receipt.at(3).ok()?.at(log_index as usize).ok()?;
+	if log.item_count().ok()? != 3 {
+		return None
+	}
+
+	let address = log.at(0).ok()?.data().ok()?;
+	if address.len() != 20 {
+		return None
+	}
+	let mut topics = Vec::new();
+	for topic in log.at(1).ok()?.iter() {
+		let topic = topic.data().ok()?;
+		if topic.len() != 32 {
+			return None
+		}
+		topics.push(H256::from_slice(topic));
+	}
+	let data = log.at(2).ok()?.data().ok()?.to_vec();
+	Some(Log { address: H160::from_slice(address), topics, data })
+}
+
+/// Helpers to build receipts and receipt proofs for tests and benchmarks.
+#[cfg(any(test, feature = "test-utils", feature = "runtime-benchmarks"))]
+pub mod fixtures {
+	use super::*;
+	use rlp::RlpStream;
+	use sp_std::vec;
+
+	/// Hex-prefix encodes the nibbles of a leaf path.
+	pub fn leaf_path(nibbles: &[u8]) -> Vec<u8> {
+		let mut encoded = Vec::with_capacity(nibbles.len() / 2 + 1);
+		let rest = if nibbles.len() % 2 == 1 {
+			encoded.push(0x30 | nibbles[0]);
+			&nibbles[1..]
+		} else {
+			encoded.push(0x20);
+			nibbles
+		};
+		encoded.extend(rest.chunks(2).map(|pair| pair[0] << 4 | pair[1]));
+		encoded
+	}
+
+	/// Encodes a trie leaf node.
+	pub fn leaf(nibbles: &[u8], value: &[u8]) -> Vec<u8> {
+		let mut stream = RlpStream::new_list(2);
+		stream.append(&leaf_path(nibbles));
+		stream.append(&value.to_vec());
+		stream.out().to_vec()
+	}
+
+	/// Encodes a successful legacy receipt carrying a `Locked` event of `contract`.
+	pub fn locked_receipt(
+		contract: H160,
+		token: H160,
+		sender: H160,
+		recipient: &[u8],
+		amount: U256,
+	) -> Vec<u8> {
+		padded_locked_receipt(contract, token, sender, recipient, amount, 256)
+	}
+
+	/// Like `locked_receipt`, with a logs bloom of `bloom_len` bytes to control the receipt size.
+	pub fn padded_locked_receipt(
+		contract: H160,
+		token: H160,
+		sender: H160,
+		recipient: &[u8],
+		amount: U256,
+		bloom_len: usize,
+	) -> Vec<u8> {
+		let mut recipient_topic = [0u8; 32];
+		recipient_topic[..recipient.len()].copy_from_slice(recipient);
+		let mut data = [0u8; 32];
+		amount.to_big_endian(&mut data);
+
+		let mut stream = RlpStream::new_list(4);
+		stream.append(&1u8);
+		stream.append(&50_000u64);
+		stream.append(&vec![0u8; bloom_len]);
+		stream.begin_list(1);
+		stream.begin_list(3);
+		stream.append(&contract.as_bytes().to_vec());
+		stream.begin_list(4);
+		stream.append(&keccak_256(LOCKED_EVENT).to_vec());
+		stream.append(&H256::from(token).as_bytes().to_vec());
+		stream.append(&H256::from(sender).as_bytes().to_vec());
+		stream.append(&recipient_topic.to_vec());
+		stream.append(&data.to_vec());
+		stream.out().to_vec()
+	}
+
+	/// Builds a receipts trie holding only `receipt` at `receipt_index` and returns its root
+	/// together with the proof of the receipt.
+	pub fn single_receipt_proof(receipt_index: u64, receipt: &[u8]) -> (H256, Vec<Vec<u8>>) {
+		let node = leaf(&to_nibbles(&rlp::encode(&receipt_index)), receipt);
+		(H256(keccak_256(&node)), vec![node])
+	}
+}
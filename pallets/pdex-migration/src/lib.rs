@@ -19,90 +19,1196 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-/// Edit this file to define custom logic or remove it if it is not needed.
-/// Learn more about FRAME and the core library of Substrate FRAME pallets:
-/// https://substrate.dev/docs/en/knowledgebase/runtime/frame
-use frame_support::{decl_error, decl_event, decl_module, decl_storage};
-use frame_support::{
-	pallet_prelude::*,
-	sp_runtime::{traits::AtLeast32BitUnsigned, SaturatedConversion},
-};
-use orml_traits::MultiCurrencyExtended;
+//! # PDEX Migration Pallet
+//!
+//! Bridges ERC20 PDEX and other registered ERC20 tokens on Ethereum to orml assets. Relayers
+//! submit Merkle-Patricia proofs of the receipts of ERC20 deposits and the pallet mints the
+//! mapped asset once enough of them agree; native PDEX can be burnt again to release ERC20 on
+//! Ethereum.
+
+pub use ethereum::EthereumHeaders;
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
 use polkadex_primitives::assets::AssetId;
-use sp_core::{H160, U256};
-use sp_runtime::traits::StaticLookup;
-
-/// Configure the pallet by specifying the parameters and types on which it depends.
-pub trait Config: frame_system::Config {
-	/// Because this pallet emits events, it depends on the runtime's definition of an event.
-	type Event: From<Event<Self>> + Into<<Self as frame_system::Config>::Event>;
-	/// Balance Type
-	type Balance: Parameter
-		+ Member
-		+ AtLeast32BitUnsigned
-		+ Default
-		+ Copy
-		+ MaybeSerializeDeserialize;
-	/// Module that handles tokens
-	type Currency: MultiCurrencyExtended<
-		Self::AccountId,
-		CurrencyId = AssetId,
-		Balance = Self::Balance,
-	>;
+use sp_core::{H160, H256, U256};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod ethereum;
+pub mod migrations;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+/// Number of decimals used by the ERC20 PDEX token on Ethereum.
+pub const ERC20_DECIMALS: u32 = 18;
+/// Number of decimals used by native PDEX and orml assets.
+pub const NATIVE_DECIMALS: u32 = 12;
+/// Maximum number of decimals of a registered ERC20 token.
+pub const MAX_ERC20_DECIMALS: u8 = 36;
+
+/// Asset an ERC20 token is minted as.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct TokenInfo {
+	/// Asset credited for deposits of the token
+	pub asset_id: AssetId,
+	/// Number of decimals of the ERC20 token
+	pub decimals: u8,
+}
+
+/// An ERC20 deposit as proven by a single relayer.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct Attestation<AccountId> {
+	/// ERC20 token contract
+	pub token: H160,
+	/// Ethereum account that locked the tokens
+	pub sender: H160,
+	/// Polkadex account to be credited
+	pub recipient: AccountId,
+	/// ERC20 amount
+	pub amount: U256,
+}
 
-	type CallOrigin: EnsureOrigin<Self::Origin, Success = H160>;
+/// Maximum amount that may be minted within a rolling window of blocks.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct PeriodLimit<BlockNumber, Balance> {
+	/// Length of the window in blocks
+	pub period: BlockNumber,
+	/// Amount that may be minted per window
+	pub limit: Balance,
 }
 
-decl_storage! {
-	trait Store for Module<T: Config> as NativePDEXMigration {
+/// Progress of a native PDEX burn towards the release of ERC20 on Ethereum.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub enum WithdrawalStatus {
+	/// Waiting for relayers to confirm the release
+	Pending,
+	/// ERC20 released by the given Ethereum transaction
+	Released(H256),
+}
+
+/// Native PDEX burnt in exchange for ERC20 on Ethereum.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct Withdrawal<AccountId, Balance, BlockNumber> {
+	/// Account whose native PDEX was burnt
+	pub who: AccountId,
+	/// Ethereum account to receive the ERC20
+	pub eth_recipient: H160,
+	/// Native amount burnt
+	pub amount: Balance,
+	/// ERC20 amount to release
+	pub erc20_amount: U256,
+	/// Block the burn happened in
+	pub requested_at: BlockNumber,
+	/// Relayers that confirmed the release and the Ethereum transaction each of them reported
+	pub confirmations: Vec<(H160, H256)>,
+	/// Current state
+	pub status: WithdrawalStatus,
+}
+
+/// A large mint waiting for its delay to pass before it is credited.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct QueuedMint<AccountId, Balance, BlockNumber> {
+	/// Deposit the mint was attested for
+	pub attestation: Attestation<AccountId>,
+	/// Asset to credit
+	pub asset_id: AssetId,
+	/// Amount to credit
+	pub balance: Balance,
+	/// Block at which the mint is credited
+	pub execute_at: BlockNumber,
+}
+
+/// Attestations collected for a single Ethereum deposit.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct MintProposal<AccountId, BlockNumber> {
+	/// Relayers that voted and the deposit each of them attested to
+	pub votes: Vec<(H160, Attestation<AccountId>)>,
+	/// Block at which the proposal is discarded if the threshold was not reached
+	pub expiry: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::Contains, transactional};
+	use frame_system::pallet_prelude::*;
+	use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+	use sp_runtime::{
+		traits::{AtLeast32BitUnsigned, CheckedAdd, One, Saturating, Zero},
+		SaturatedConversion,
+	};
+	use sp_std::convert::TryFrom;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Balance Type
+		type Balance: Parameter
+			+ Member
+			+ AtLeast32BitUnsigned
+			+ Default
+			+ Copy
+			+ MaybeSerializeDeserialize;
+		/// Module that handles tokens
+		type Currency: MultiCurrencyExtended<
+			Self::AccountId,
+			CurrencyId = AssetId,
+			Balance = Self::Balance,
+		>;
+		/// Origin of relayer attestations, resolving to the relayer's Ethereum address. See
+		/// [`EnsureRelayer`] for an origin resolving signed relayer accounts.
+		type CallOrigin: EnsureOrigin<Self::Origin, Success = H160>;
+		/// Origin that manages the relayer set, threshold, mint limits and mint delay
+		type GovernanceOrigin: EnsureOrigin<Self::Origin>;
+		/// Origin that can cancel queued mints and pause the bridge
+		type GuardianOrigin: EnsureOrigin<Self::Origin>;
+		/// Receipts roots of final Ethereum blocks that deposit proofs are checked against
+		type EthereumHeaders: EthereumHeaders;
+		/// Assets that ERC20 tokens may be registered as
+		type ActiveAssets: Contains<AssetId>;
+		/// Maximum number of relayers
+		#[pallet::constant]
+		type MaxRelayers: Get<u32>;
+		/// Number of blocks a mint proposal stays open before it expires
+		#[pallet::constant]
+		type ProposalLifetime: Get<Self::BlockNumber>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Address of ERC20 to Native PDEX migration contract
+	#[pallet::storage]
+	#[pallet::getter(fn address)]
+	pub type Address<T> = StorageValue<_, H160, ValueQuery>;
+
+	/// Ethereum deposits already minted, keyed by deposit id and log index, with the
+	/// block they were processed in
+	#[pallet::storage]
+	#[pallet::getter(fn processed_deposits)]
+	pub type ProcessedDeposits<T: Config> =
+		StorageDoubleMap<_, Identity, H256, Twox64Concat, u64, T::BlockNumber, OptionQuery>;
+
+	/// ERC20 tokens that may be bridged and the assets they are minted as
+	#[pallet::storage]
+	#[pallet::getter(fn registered_tokens)]
+	pub type RegisteredTokens<T> = StorageMap<_, Blake2_128Concat, H160, TokenInfo, OptionQuery>;
+
+	/// Receipts roots agreed on by `Threshold` relayers, keyed by Ethereum block hash
+	#[pallet::storage]
+	#[pallet::getter(fn receipts_roots)]
+	pub type ReceiptsRoots<T> = StorageMap<_, Identity, H256, H256, OptionQuery>;
+
+	/// Receipts roots reported by relayers for blocks whose root was not agreed on yet
+	#[pallet::storage]
+	#[pallet::getter(fn receipts_root_votes)]
+	pub type ReceiptsRootVotes<T> = StorageMap<_, Identity, H256, Vec<(H160, H256)>, ValueQuery>;
+
+	/// Ethereum addresses of the relayers allowed to attest deposits
+	#[pallet::storage]
+	#[pallet::getter(fn relayers)]
+	pub type Relayers<T> = StorageValue<_, Vec<H160>, ValueQuery>;
+
+	/// Ethereum address of each relayer, keyed by the account it signs extrinsics with
+	#[pallet::storage]
+	#[pallet::getter(fn relayer_accounts)]
+	pub type RelayerAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, H160, OptionQuery>;
+
+	/// Number of matching attestations required to mint
+	#[pallet::storage]
+	#[pallet::getter(fn threshold)]
+	pub type Threshold<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Open mint proposals, keyed by deposit id and log index
+	#[pallet::storage]
+	#[pallet::getter(fn proposals)]
+	pub type Proposals<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		H256,
+		Twox64Concat,
+		u64,
+		MintProposal<T::AccountId, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Proposals to discard at a given block
+	#[pallet::storage]
+	pub type ProposalExpiries<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, Vec<(H256, u64)>, ValueQuery>;
+
+	/// Maximum amount of each asset that may ever be minted by the bridge
+	#[pallet::storage]
+	#[pallet::getter(fn migration_cap)]
+	pub type MigrationCap<T: Config> =
+		StorageMap<_, Twox64Concat, AssetId, T::Balance, OptionQuery>;
+
+	/// Amount of each asset minted by the bridge so far
+	#[pallet::storage]
+	#[pallet::getter(fn total_minted)]
+	pub type TotalMinted<T: Config> = StorageMap<_, Twox64Concat, AssetId, T::Balance, ValueQuery>;
+
+	/// Maximum amount of each asset minted per period
+	#[pallet::storage]
+	#[pallet::getter(fn mint_period_limit)]
+	pub type MintPeriodLimit<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		AssetId,
+		PeriodLimit<T::BlockNumber, T::Balance>,
+		OptionQuery,
+	>;
+
+	/// Start block of the current period of each asset and the amount minted in it
+	#[pallet::storage]
+	#[pallet::getter(fn current_period)]
+	pub type CurrentPeriod<T: Config> =
+		StorageMap<_, Twox64Concat, AssetId, (T::BlockNumber, T::Balance), ValueQuery>;
+
+	/// Maximum amount of each asset a single account may receive from the bridge
+	#[pallet::storage]
+	#[pallet::getter(fn recipient_limit)]
+	pub type RecipientLimit<T: Config> =
+		StorageMap<_, Twox64Concat, AssetId, T::Balance, OptionQuery>;
+
+	/// Amount of each asset minted to each account so far
+	#[pallet::storage]
+	#[pallet::getter(fn minted_per_recipient)]
+	pub type MintedPerRecipient<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// Mints of an asset larger than this amount are queued for its `MintDelay` blocks
+	#[pallet::storage]
+	#[pallet::getter(fn delay_threshold)]
+	pub type DelayThreshold<T: Config> =
+		StorageMap<_, Twox64Concat, AssetId, T::Balance, OptionQuery>;
+
+	/// Number of blocks a large mint of an asset is queued for
+	#[pallet::storage]
+	#[pallet::getter(fn mint_delay)]
+	pub type MintDelay<T: Config> =
+		StorageMap<_, Twox64Concat, AssetId, T::BlockNumber, ValueQuery>;
+
+	/// Mints waiting to be credited, keyed by deposit id and log index
+	#[pallet::storage]
+	#[pallet::getter(fn queued_mints)]
+	pub type QueuedMints<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		H256,
+		Twox64Concat,
+		u64,
+		QueuedMint<T::AccountId, T::Balance, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Queued mints to credit at a given block
+	#[pallet::storage]
+	pub type MintQueue<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, Vec<(H256, u64)>, ValueQuery>;
+
+	/// Whether all bridge activity is halted
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	pub type Paused<T> = StorageValue<_, bool, ValueQuery>;
+
+	/// Nonce assigned to the next withdrawal
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_nonce)]
+	pub type WithdrawalNonce<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Withdrawals to Ethereum, keyed by nonce
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawals)]
+	pub type Withdrawals<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		u64,
+		Withdrawal<T::AccountId, T::Balance, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
 		/// Address of ERC20 to Native PDEX migration contract
-		Address get(fn address) config(): H160;
+		pub address: H160,
+		/// Initial relayer set, as the accounts relayers sign with and their Ethereum addresses
+		pub relayers: Vec<(T::AccountId, H160)>,
+		/// Number of matching attestations required to mint
+		pub threshold: u32,
+		/// Maximum amounts of assets the bridge may mint, usually the ERC20 supplies
+		pub migration_caps: Vec<(AssetId, T::Balance)>,
+		/// Bridged ERC20 tokens with the assets they are minted as and their decimals
+		pub tokens: Vec<(H160, AssetId, u8)>,
 	}
-}
 
-// Pallets use events to inform users when important changes are made.
-// https://substrate.dev/docs/en/knowledgebase/runtime/events
-decl_event!(
-	pub enum Event<T>
-	where
-		AccountId = <T as frame_system::Config>::AccountId,
-		Balance = <T as Config>::Balance,
-	{
-		NativePDEXMinted(H160, H160, AccountId, U256, Balance),
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self {
+				address: Default::default(),
+				relayers: Default::default(),
+				threshold: Default::default(),
+				migration_caps: Default::default(),
+				tokens: Default::default(),
+			}
+		}
 	}
-);
 
-// Errors inform users that something went wrong.
-decl_error! {
-	pub enum Error for Module<T: Config> {
-		/// The submitted payload could not be decoded.
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			assert!(self.threshold > 0, "Relayer threshold must not be zero");
+			assert!(
+				self.threshold as usize <= self.relayers.len(),
+				"Relayer threshold exceeds the number of relayers"
+			);
+			assert!(
+				self.relayers.len() as u32 <= T::MaxRelayers::get(),
+				"Too many relayers in genesis"
+			);
+			Address::<T>::put(self.address);
+			let mut relayers = Vec::with_capacity(self.relayers.len());
+			for (account, relayer) in &self.relayers {
+				assert!(
+					!relayers.contains(relayer) && !RelayerAccounts::<T>::contains_key(account),
+					"Duplicate relayer in genesis"
+				);
+				RelayerAccounts::<T>::insert(account, relayer);
+				relayers.push(*relayer);
+			}
+			Relayers::<T>::put(relayers);
+			Threshold::<T>::put(self.threshold);
+			for (asset_id, cap) in &self.migration_caps {
+				MigrationCap::<T>::insert(asset_id, cap);
+			}
+			for (token, asset_id, decimals) in &self.tokens {
+				assert!(*decimals <= MAX_ERC20_DECIMALS, "Too many decimals in genesis token");
+				RegisteredTokens::<T>::insert(
+					token,
+					TokenInfo { asset_id: *asset_id, decimals: *decimals },
+				);
+			}
+		}
+	}
+
+	// Pallets use events to inform users when important changes are made.
+	// https://substrate.dev/docs/en/knowledgebase/runtime/events
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		T::Balance = "Balance",
+		T::BlockNumber = "BlockNumber"
+	)]
+	pub enum Event<T: Config> {
+		/// Native PDEX minted for an ERC20 deposit.
+		/// \[deposit_id, log_index, token, sender, recipient, erc20_amount, native_amount\]
+		NativePDEXMinted(H256, u64, H160, H160, T::AccountId, U256, T::Balance),
+		/// A relayer attested a deposit. \[deposit_id, log_index, relayer, matching_votes\]
+		MintAttested(H256, u64, H160, u32),
+		/// A mint proposal expired without reaching the threshold. \[deposit_id, log_index\]
+		ProposalExpired(H256, u64),
+		/// A relayer was added. \[account, relayer\]
+		RelayerAdded(T::AccountId, H160),
+		/// A relayer was removed. \[account, relayer\]
+		RelayerRemoved(T::AccountId, H160),
+		/// The attestation threshold changed. \[threshold\]
+		ThresholdChanged(u32),
+		/// The total migration cap of an asset changed. \[asset_id, cap\]
+		MigrationCapChanged(AssetId, Option<T::Balance>),
+		/// The per-period mint limit of an asset changed. \[asset_id, limit\]
+		MintPeriodLimitChanged(AssetId, Option<PeriodLimit<T::BlockNumber, T::Balance>>),
+		/// The per-recipient mint limit of an asset changed. \[asset_id, limit\]
+		RecipientLimitChanged(AssetId, Option<T::Balance>),
+		/// Native PDEX was burnt to release ERC20 on Ethereum.
+		/// \[nonce, who, eth_recipient, native_amount, erc20_amount\]
+		WithdrawalRequested(u64, T::AccountId, H160, T::Balance, U256),
+		/// A relayer confirmed the release of a withdrawal.
+		/// \[nonce, relayer, eth_tx_hash, matching_confirmations\]
+		WithdrawalConfirmed(u64, H160, H256, u32),
+		/// A withdrawal was released on Ethereum. \[nonce, eth_tx_hash\]
+		WithdrawalReleased(u64, H256),
+		/// A mint was queued. \[deposit_id, log_index, asset_id, recipient, amount, execute_at\]
+		MintQueued(H256, u64, AssetId, T::AccountId, T::Balance, T::BlockNumber),
+		/// A queued mint was cancelled by the guardian. \[deposit_id, log_index\]
+		MintCancelled(H256, u64),
+		/// A queued mint could not be credited. \[deposit_id, log_index\]
+		QueuedMintFailed(H256, u64),
+		/// The mint delay of an asset changed. \[asset_id, threshold, delay\]
+		MintDelayChanged(AssetId, Option<T::Balance>, T::BlockNumber),
+		/// An ERC20 token other than PDEX was minted as an orml asset.
+		/// \[deposit_id, log_index, token, asset_id, recipient, erc20_amount, amount\]
+		ERC20Minted(H256, u64, H160, AssetId, T::AccountId, U256, T::Balance),
+		/// An ERC20 token was registered. \[token, asset_id, decimals\]
+		TokenRegistered(H160, AssetId, u8),
+		/// An ERC20 token was deregistered. \[token\]
+		TokenDeregistered(H160),
+		/// A relayer reported the receipts root of an Ethereum block.
+		/// \[block_hash, receipts_root, relayer, matching_votes\]
+		ReceiptsRootAttested(H256, H256, H160, u32),
+		/// The receipts root of an Ethereum block was agreed on. \[block_hash, receipts_root\]
+		ReceiptsRootSubmitted(H256, H256),
+		/// All bridge activity was halted.
+		BridgePaused,
+		/// Bridge activity was resumed.
+		BridgeResumed,
+		/// A relayer attesting data that conflicts with the agreed data was removed.
+		/// \[relayer\]
+		RelayerSlashed(H160),
+		/// A relayer attested conflicting data but was kept because the remaining set could no
+		/// longer reach the threshold. \[relayer\]
+		ConflictingAttestation(H160),
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The receipt or its log could not be decoded as a `Locked` event.
 		InvalidPayload,
+		/// The converted amount does not fit into the native balance type.
+		AmountOverflow,
+		/// The ERC20 amount has precision below the smallest native unit.
+		AmountHasDust,
+		/// The Ethereum deposit has already been minted.
+		AlreadyProcessed,
+		/// The origin is not a registered relayer.
+		NotRelayer,
+		/// The relayer already attested this deposit.
+		AlreadyAttested,
+		/// The deposit log was not emitted by the migration contract.
+		InvalidContract,
+		/// The ERC20 token is not registered.
+		TokenNotRegistered,
+		/// The ERC20 token is already registered.
+		TokenAlreadyRegistered,
+		/// The ERC20 token has more than `MAX_ERC20_DECIMALS` decimals.
+		InvalidDecimals,
+		/// The receipts root of the Ethereum block is not known.
+		UnknownBlock,
+		/// The receipt proof does not match the receipts root.
+		InvalidProof,
+		/// The relayer is already registered.
+		RelayerAlreadyExists,
+		/// The relayer is not registered.
+		RelayerNotFound,
+		/// The relayer set is full.
+		TooManyRelayers,
+		/// The threshold is zero or larger than the relayer set.
+		InvalidThreshold,
+		/// The mint would exceed the total migration cap.
+		MigrationCapExceeded,
+		/// The mint would exceed the limit of the current period.
+		PeriodLimitExceeded,
+		/// The mint would exceed the recipient's limit.
+		RecipientLimitExceeded,
+		/// The period of a mint limit must be at least one block.
+		InvalidPeriod,
+		/// The amount must not be zero.
+		ZeroAmount,
+		/// No withdrawal exists with the given nonce.
+		WithdrawalNotFound,
+		/// The withdrawal was already released.
+		WithdrawalAlreadyReleased,
+		/// The relayer already confirmed this withdrawal.
+		AlreadyConfirmed,
+		/// The bridge is paused.
+		BridgePaused,
+		/// No queued mint exists for the deposit.
+		QueuedMintNotFound,
+		/// The receipts root of the Ethereum block is already known.
+		ReceiptsRootAlreadyKnown,
+		/// The asset is not registered or not active.
+		AssetNotActive,
 	}
-}
 
-// Dispatchable functions allows users to interact with the pallet and invoke state changes.
-// These functions materialize as "extrinsics", which are often compared to transactions.
-// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-decl_module! {
-	pub struct Module<T: Config> for enum Call where origin: T::Origin {
-		// Errors must be initialized if they are used by the pallet.
-		type Error = Error<T>;
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let expired = ProposalExpiries::<T>::take(now);
+			let expired_count = expired.len() as u32;
+			for (deposit_id, log_index) in expired {
+				if Proposals::<T>::take(deposit_id, log_index).is_some() {
+					Self::deposit_event(Event::ProposalExpired(deposit_id, log_index));
+				}
+			}
+
+			let due = MintQueue::<T>::take(now);
+			let due_count = due.len() as u32;
+			let paused = Self::paused();
+			for (deposit_id, log_index) in due {
+				if paused {
+					MintQueue::<T>::append(now + One::one(), (deposit_id, log_index));
+				} else if let Some(mint) = QueuedMints::<T>::take(deposit_id, log_index) {
+					Self::credit(deposit_id, log_index, mint);
+				}
+			}
+			T::WeightInfo::on_initialize(expired_count, due_count)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			migrations::v1::pre_migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migrations::v1::post_migrate::<T>()
+		}
+	}
+
+	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
+	// These functions materialize as "extrinsics", which are often compared to transactions.
+	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Attest the ERC20 deposit logged at `log_index` of receipt `receipt_index` in Ethereum
+		/// block `block_hash`. `proof` holds the receipts trie nodes from the root to the receipt.
+		///
+		/// The deposit is minted once `Threshold` relayers attested identical data. Deposits of
+		/// unregistered tokens are rejected.
+		#[pallet::weight(
+			T::WeightInfo::mint(T::MaxRelayers::get(), Pallet::<T>::proof_size(proof))
+		)]
+		#[transactional]
+		pub fn mint(
+			origin: OriginFor<T>,
+			block_hash: H256,
+			receipt_index: u64,
+			proof: Vec<Vec<u8>>,
+			log_index: u64,
+		) -> DispatchResult {
+			let relayer = T::CallOrigin::ensure_origin(origin)?;
+			ensure!(!Self::paused(), Error::<T>::BridgePaused);
+			ensure!(Self::relayers().contains(&relayer), Error::<T>::NotRelayer);
+			let deposit_id = Self::deposit_id(block_hash, receipt_index);
+			ensure!(
+				!Self::is_deposit_processed(deposit_id, log_index),
+				Error::<T>::AlreadyProcessed
+			);
+
+			let attestation = Self::verify_deposit(block_hash, receipt_index, &proof, log_index)?;
+			let token =
+				Self::registered_tokens(attestation.token).ok_or(Error::<T>::TokenNotRegistered)?;
+			Self::convert_amount(attestation.amount, token.decimals)?;
 
-		fn deposit_event() = default;
+			let mut proposal = Proposals::<T>::get(deposit_id, log_index).unwrap_or_else(|| {
+				let expiry =
+					frame_system::Pallet::<T>::block_number() + T::ProposalLifetime::get();
+				ProposalExpiries::<T>::append(expiry, (deposit_id, log_index));
+				MintProposal { votes: Vec::new(), expiry }
+			});
+			ensure!(
+				proposal.votes.iter().all(|(voter, _)| *voter != relayer),
+				Error::<T>::AlreadyAttested
+			);
+			Self::prune_votes(&mut proposal.votes);
+			proposal.votes.push((relayer, attestation.clone()));
 
-		#[weight = 10000]
-		pub fn mint(origin, token: H160, sender: H160, recipient: <T::Lookup as StaticLookup>::Source, amount: U256) -> DispatchResult {
-			let who = T::CallOrigin::ensure_origin(origin)?;
-			if who != Address::get() {
-				return Err(DispatchError::BadOrigin);
+			let matching =
+				proposal.votes.iter().filter(|(_, vote)| *vote == attestation).count() as u32;
+			Self::deposit_event(Event::MintAttested(deposit_id, log_index, relayer, matching));
+
+			if matching < Self::threshold() {
+				Proposals::<T>::insert(deposit_id, log_index, proposal);
+				return Ok(())
+			}
+
+			Proposals::<T>::remove(deposit_id, log_index);
+			Self::slash_conflicting(&proposal.votes, &attestation);
+			Self::execute_mint(deposit_id, log_index, attestation, token)
+		}
+
+		/// Report the receipts root of the final Ethereum block `block_hash`.
+		///
+		/// The root is stored once `Threshold` relayers reported the same root and can not be
+		/// changed afterwards. Relayers reporting a different root are slashed.
+		#[pallet::weight(T::WeightInfo::submit_receipts_root(T::MaxRelayers::get()))]
+		pub fn submit_receipts_root(
+			origin: OriginFor<T>,
+			block_hash: H256,
+			receipts_root: H256,
+		) -> DispatchResult {
+			let relayer = T::CallOrigin::ensure_origin(origin)?;
+			ensure!(!Self::paused(), Error::<T>::BridgePaused);
+			ensure!(Self::relayers().contains(&relayer), Error::<T>::NotRelayer);
+			if let Some(known) = Self::receipts_roots(block_hash) {
+				ensure!(known != receipts_root, Error::<T>::ReceiptsRootAlreadyKnown);
+				Self::slash_relayer(relayer);
+				return Ok(())
 			}
 
-			let recipient = T::Lookup::lookup(recipient)?;
-			// TODO: Convert U256 amount to T::Balance amount
-			// T::Currency::deposit(AssetId::POLKADEX, &recipient, amount)?;
-			Self::deposit_event(RawEvent::NativePDEXMinted(token, sender, recipient, amount,0_u128.saturated_into()));
+			let mut votes = Self::receipts_root_votes(block_hash);
+			ensure!(votes.iter().all(|(voter, _)| *voter != relayer), Error::<T>::AlreadyAttested);
+			Self::prune_votes(&mut votes);
+			votes.push((relayer, receipts_root));
+			let matching = votes.iter().filter(|(_, root)| *root == receipts_root).count() as u32;
+			Self::deposit_event(Event::ReceiptsRootAttested(
+				block_hash,
+				receipts_root,
+				relayer,
+				matching,
+			));
 
+			if matching < Self::threshold() {
+				ReceiptsRootVotes::<T>::insert(block_hash, votes);
+				return Ok(())
+			}
+
+			ReceiptsRootVotes::<T>::remove(block_hash);
+			ReceiptsRoots::<T>::insert(block_hash, receipts_root);
+			Self::deposit_event(Event::ReceiptsRootSubmitted(block_hash, receipts_root));
+			Self::slash_conflicting(&votes, &receipts_root);
+			Ok(())
+		}
+
+		/// Allow deposits of the ERC20 `token`, minting them as the active asset `asset_id`.
+		#[pallet::weight(T::WeightInfo::register_token())]
+		pub fn register_token(
+			origin: OriginFor<T>,
+			token: H160,
+			asset_id: AssetId,
+			decimals: u8,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(decimals <= MAX_ERC20_DECIMALS, Error::<T>::InvalidDecimals);
+			ensure!(T::ActiveAssets::contains(&asset_id), Error::<T>::AssetNotActive);
+			ensure!(
+				!RegisteredTokens::<T>::contains_key(token),
+				Error::<T>::TokenAlreadyRegistered
+			);
+			RegisteredTokens::<T>::insert(token, TokenInfo { asset_id, decimals });
+			Self::deposit_event(Event::TokenRegistered(token, asset_id, decimals));
 			Ok(())
 		}
+
+		/// Stop accepting deposits of the ERC20 `token`.
+		#[pallet::weight(T::WeightInfo::deregister_token())]
+		pub fn deregister_token(origin: OriginFor<T>, token: H160) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			RegisteredTokens::<T>::take(token).ok_or(Error::<T>::TokenNotRegistered)?;
+			Self::deposit_event(Event::TokenDeregistered(token));
+			Ok(())
+		}
+
+		/// Add the relayer signing with `account` and attesting as the Ethereum address
+		/// `relayer` to the attesting set.
+		#[pallet::weight(T::WeightInfo::add_relayer())]
+		pub fn add_relayer(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			relayer: H160,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				!RelayerAccounts::<T>::contains_key(&account),
+				Error::<T>::RelayerAlreadyExists
+			);
+			Relayers::<T>::try_mutate(|relayers| -> DispatchResult {
+				ensure!(!relayers.contains(&relayer), Error::<T>::RelayerAlreadyExists);
+				ensure!(
+					(relayers.len() as u32) < T::MaxRelayers::get(),
+					Error::<T>::TooManyRelayers
+				);
+				relayers.push(relayer);
+				Ok(())
+			})?;
+			RelayerAccounts::<T>::insert(&account, relayer);
+			Self::deposit_event(Event::RelayerAdded(account, relayer));
+			Ok(())
+		}
+
+		/// Remove the relayer signing with `account` from the attesting set. The remaining set
+		/// must still be able to reach the threshold.
+		#[pallet::weight(T::WeightInfo::remove_relayer())]
+		pub fn remove_relayer(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let relayer = Self::relayer_accounts(&account).ok_or(Error::<T>::RelayerNotFound)?;
+			Relayers::<T>::try_mutate(|relayers| -> DispatchResult {
+				ensure!(relayers.len() as u32 > Self::threshold(), Error::<T>::InvalidThreshold);
+				relayers.retain(|r| *r != relayer);
+				Ok(())
+			})?;
+			RelayerAccounts::<T>::remove(&account);
+			Self::deposit_event(Event::RelayerRemoved(account, relayer));
+			Ok(())
+		}
+
+		/// Set the number of matching attestations required to mint.
+		#[pallet::weight(T::WeightInfo::set_threshold())]
+		pub fn set_threshold(origin: OriginFor<T>, threshold: u32) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				threshold > 0 && threshold as usize <= Self::relayers().len(),
+				Error::<T>::InvalidThreshold
+			);
+			Threshold::<T>::put(threshold);
+			Self::deposit_event(Event::ThresholdChanged(threshold));
+			Ok(())
+		}
+
+		/// Set the maximum amount of `asset_id` the bridge may ever mint.
+		#[pallet::weight(T::WeightInfo::set_migration_cap())]
+		pub fn set_migration_cap(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			cap: Option<T::Balance>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			MigrationCap::<T>::set(asset_id, cap);
+			Self::deposit_event(Event::MigrationCapChanged(asset_id, cap));
+			Ok(())
+		}
+
+		/// Set the maximum amount of `asset_id` the bridge may mint per period.
+		#[pallet::weight(T::WeightInfo::set_mint_period_limit())]
+		pub fn set_mint_period_limit(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			limit: Option<PeriodLimit<T::BlockNumber, T::Balance>>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			if let Some(PeriodLimit { period, .. }) = limit {
+				ensure!(!period.is_zero(), Error::<T>::InvalidPeriod);
+			}
+			MintPeriodLimit::<T>::set(asset_id, limit);
+			Self::deposit_event(Event::MintPeriodLimitChanged(asset_id, limit));
+			Ok(())
+		}
+
+		/// Set the maximum amount of `asset_id` a single account may receive from the bridge.
+		#[pallet::weight(T::WeightInfo::set_recipient_limit())]
+		pub fn set_recipient_limit(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			limit: Option<T::Balance>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			RecipientLimit::<T>::set(asset_id, limit);
+			Self::deposit_event(Event::RecipientLimitChanged(asset_id, limit));
+			Ok(())
+		}
+
+		/// Burn native PDEX to have the equivalent ERC20 released to `eth_recipient`.
+		#[pallet::weight(T::WeightInfo::burn_for_erc20())]
+		#[transactional]
+		pub fn burn_for_erc20(
+			origin: OriginFor<T>,
+			amount: T::Balance,
+			eth_recipient: H160,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!Self::paused(), Error::<T>::BridgePaused);
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let erc20_amount = Self::to_erc20_amount(amount);
+			T::Currency::withdraw(AssetId::POLKADEX, &who, amount)?;
+
+			let nonce = WithdrawalNonce::<T>::mutate(|nonce| {
+				let current = *nonce;
+				*nonce = nonce.wrapping_add(1);
+				current
+			});
+			Withdrawals::<T>::insert(
+				nonce,
+				Withdrawal {
+					who: who.clone(),
+					eth_recipient,
+					amount,
+					erc20_amount,
+					requested_at: frame_system::Pallet::<T>::block_number(),
+					confirmations: Vec::new(),
+					status: WithdrawalStatus::Pending,
+				},
+			);
+			Self::deposit_event(Event::WithdrawalRequested(
+				nonce,
+				who,
+				eth_recipient,
+				amount,
+				erc20_amount,
+			));
+			Ok(())
+		}
+
+		/// Confirm that the ERC20 for withdrawal `nonce` was released by `eth_tx_hash`. The
+		/// withdrawal is finalised once `Threshold` relayers reported the same transaction.
+		#[pallet::weight(T::WeightInfo::confirm_withdrawal(T::MaxRelayers::get()))]
+		pub fn confirm_withdrawal(
+			origin: OriginFor<T>,
+			nonce: u64,
+			eth_tx_hash: H256,
+		) -> DispatchResult {
+			let relayer = T::CallOrigin::ensure_origin(origin)?;
+			ensure!(!Self::paused(), Error::<T>::BridgePaused);
+			ensure!(Self::relayers().contains(&relayer), Error::<T>::NotRelayer);
+
+			Withdrawals::<T>::try_mutate(nonce, |withdrawal| -> DispatchResult {
+				let withdrawal = withdrawal.as_mut().ok_or(Error::<T>::WithdrawalNotFound)?;
+				ensure!(
+					withdrawal.status == WithdrawalStatus::Pending,
+					Error::<T>::WithdrawalAlreadyReleased
+				);
+				ensure!(
+					withdrawal.confirmations.iter().all(|(r, _)| *r != relayer),
+					Error::<T>::AlreadyConfirmed
+				);
+				Self::prune_votes(&mut withdrawal.confirmations);
+				withdrawal.confirmations.push((relayer, eth_tx_hash));
+
+				let matching = withdrawal
+					.confirmations
+					.iter()
+					.filter(|(_, hash)| *hash == eth_tx_hash)
+					.count() as u32;
+				Self::deposit_event(Event::WithdrawalConfirmed(
+					nonce,
+					relayer,
+					eth_tx_hash,
+					matching,
+				));
+				if matching >= Self::threshold() {
+					Self::slash_conflicting(&withdrawal.confirmations, &eth_tx_hash);
+					withdrawal.status = WithdrawalStatus::Released(eth_tx_hash);
+					Self::deposit_event(Event::WithdrawalReleased(nonce, eth_tx_hash));
+				}
+				Ok(())
+			})
+		}
+
+		/// Queue mints of `asset_id` larger than `threshold` for `delay` blocks before crediting
+		/// them.
+		#[pallet::weight(T::WeightInfo::set_mint_delay())]
+		pub fn set_mint_delay(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			threshold: Option<T::Balance>,
+			delay: T::BlockNumber,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			DelayThreshold::<T>::set(asset_id, threshold);
+			MintDelay::<T>::insert(asset_id, delay);
+			Self::deposit_event(Event::MintDelayChanged(asset_id, threshold, delay));
+			Ok(())
+		}
+
+		/// Cancel a queued mint. The deposit stays processed and is never credited, and the
+		/// cancelled amount no longer counts towards the migration limits.
+		#[pallet::weight(T::WeightInfo::cancel_mint())]
+		pub fn cancel_mint(origin: OriginFor<T>, deposit_id: H256, log_index: u64) -> DispatchResult {
+			T::GuardianOrigin::ensure_origin(origin)?;
+			let mint = QueuedMints::<T>::take(deposit_id, log_index)
+				.ok_or(Error::<T>::QueuedMintNotFound)?;
+			TotalMinted::<T>::mutate(mint.asset_id, |total| {
+				*total = total.saturating_sub(mint.balance)
+			});
+			MintedPerRecipient::<T>::mutate(
+				mint.asset_id,
+				&mint.attestation.recipient,
+				|received| *received = received.saturating_sub(mint.balance),
+			);
+			// Only the period the mint was queued in still counts it.
+			let queued_at = Self::processed_deposits(deposit_id, log_index);
+			if CurrentPeriod::<T>::contains_key(mint.asset_id) {
+				CurrentPeriod::<T>::mutate(mint.asset_id, |(start, minted)| {
+					if queued_at.map_or(false, |at| at >= *start) {
+						*minted = minted.saturating_sub(mint.balance);
+					}
+				});
+			}
+			Self::deposit_event(Event::MintCancelled(deposit_id, log_index));
+			Ok(())
+		}
+
+		/// Halt all bridge activity, including queued mints.
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+			T::GuardianOrigin::ensure_origin(origin)?;
+			Paused::<T>::put(true);
+			Self::deposit_event(Event::BridgePaused);
+			Ok(())
+		}
+
+		/// Resume bridge activity.
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+			T::GuardianOrigin::ensure_origin(origin)?;
+			Paused::<T>::put(false);
+			Self::deposit_event(Event::BridgeResumed);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Checks the receipt proof against the known receipts root and decodes the `Locked`
+		/// event at `log_index` of the receipt.
+		fn verify_deposit(
+			block_hash: H256,
+			receipt_index: u64,
+			proof: &[Vec<u8>],
+			log_index: u64,
+		) -> Result<Attestation<T::AccountId>, DispatchError> {
+			let receipts_root =
+				T::EthereumHeaders::receipts_root(block_hash).ok_or(Error::<T>::UnknownBlock)?;
+			let receipt = ethereum::verify_receipt_proof(receipts_root, receipt_index, proof)
+				.ok_or(Error::<T>::InvalidProof)?;
+			let log =
+				ethereum::receipt_log(&receipt, log_index).ok_or(Error::<T>::InvalidPayload)?;
+			ensure!(log.address == Self::address(), Error::<T>::InvalidContract);
+			let (token, sender, recipient, amount) =
+				log.decode_locked::<T::AccountId>().ok_or(Error::<T>::InvalidPayload)?;
+			Ok(Attestation { token, sender, recipient, amount })
+		}
+
+		fn execute_mint(
+			deposit_id: H256,
+			log_index: u64,
+			attestation: Attestation<T::AccountId>,
+			token: TokenInfo,
+		) -> DispatchResult {
+			let balance = Self::convert_amount(attestation.amount, token.decimals)?;
+			let asset_id = token.asset_id;
+			Self::note_minted(asset_id, &attestation.recipient, balance)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ProcessedDeposits::<T>::insert(deposit_id, log_index, now);
+
+			let delay = Self::mint_delay(asset_id);
+			match Self::delay_threshold(asset_id) {
+				Some(threshold) if balance > threshold && !delay.is_zero() => {
+					let execute_at = now.saturating_add(delay);
+					Self::deposit_event(Event::MintQueued(
+						deposit_id,
+						log_index,
+						asset_id,
+						attestation.recipient.clone(),
+						balance,
+						execute_at,
+					));
+					QueuedMints::<T>::insert(
+						deposit_id,
+						log_index,
+						QueuedMint { attestation, asset_id, balance, execute_at },
+					);
+					MintQueue::<T>::append(execute_at, (deposit_id, log_index));
+					Ok(())
+				},
+				_ => Self::deposit_mint(deposit_id, log_index, asset_id, attestation, balance),
+			}
+		}
+
+		/// Credits `balance` of `asset_id` to the recipient of the deposit.
+		fn deposit_mint(
+			deposit_id: H256,
+			log_index: u64,
+			asset_id: AssetId,
+			attestation: Attestation<T::AccountId>,
+			balance: T::Balance,
+		) -> DispatchResult {
+			T::Currency::deposit(asset_id, &attestation.recipient, balance)?;
+			let Attestation { token, sender, recipient, amount } = attestation;
+			Self::deposit_event(if asset_id == AssetId::POLKADEX {
+				Event::NativePDEXMinted(
+					deposit_id, log_index, token, sender, recipient, amount, balance,
+				)
+			} else {
+				Event::ERC20Minted(deposit_id, log_index, token, asset_id, recipient, amount, balance)
+			});
+			Ok(())
+		}
+
+		/// Credits a mint that was held in the queue.
+		fn credit(
+			deposit_id: H256,
+			log_index: u64,
+			mint: QueuedMint<T::AccountId, T::Balance, T::BlockNumber>,
+		) {
+			let QueuedMint { attestation, asset_id, balance, .. } = mint;
+			if Self::deposit_mint(deposit_id, log_index, asset_id, attestation, balance).is_err() {
+				Self::deposit_event(Event::QueuedMintFailed(deposit_id, log_index));
+			}
+		}
+
+		/// Checks a mint of `amount` of `asset_id` to `recipient` against the migration cap, the
+		/// period limit and the recipient limit of the asset, and records it in the cumulative
+		/// totals.
+		fn note_minted(
+			asset_id: AssetId,
+			recipient: &T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let total = Self::total_minted(asset_id)
+				.checked_add(&amount)
+				.ok_or(Error::<T>::AmountOverflow)?;
+			if let Some(cap) = Self::migration_cap(asset_id) {
+				ensure!(total <= cap, Error::<T>::MigrationCapExceeded);
+			}
+
+			if let Some(PeriodLimit { period, limit }) = Self::mint_period_limit(asset_id) {
+				let now = frame_system::Pallet::<T>::block_number();
+				let (mut start, mut minted) = Self::current_period(asset_id);
+				if now >= start.saturating_add(period) {
+					start = now;
+					minted = Zero::zero();
+				}
+				minted = minted.saturating_add(amount);
+				ensure!(minted <= limit, Error::<T>::PeriodLimitExceeded);
+				CurrentPeriod::<T>::insert(asset_id, (start, minted));
+			}
+
+			let received = Self::minted_per_recipient(asset_id, recipient).saturating_add(amount);
+			if let Some(limit) = Self::recipient_limit(asset_id) {
+				ensure!(received <= limit, Error::<T>::RecipientLimitExceeded);
+			}
+
+			TotalMinted::<T>::insert(asset_id, total);
+			MintedPerRecipient::<T>::insert(asset_id, recipient, received);
+			Ok(())
+		}
+
+		/// Drops the votes of relayers that are no longer in the attesting set, so that they do
+		/// not count towards the threshold.
+		fn prune_votes<V>(votes: &mut Vec<(H160, V)>) {
+			let relayers = Self::relayers();
+			votes.retain(|(voter, _)| relayers.contains(voter));
+		}
+
+		/// Slashes every relayer whose vote differs from the `agreed` one.
+		fn slash_conflicting<V: PartialEq>(votes: &[(H160, V)], agreed: &V) {
+			for (voter, _) in votes.iter().filter(|(_, vote)| vote != agreed) {
+				Self::slash_relayer(*voter);
+			}
+		}
+
+		/// Removes `relayer` from the attesting set for attesting conflicting data. The relayer is
+		/// kept if the remaining set could no longer reach the threshold.
+		fn slash_relayer(relayer: H160) {
+			let threshold = Self::threshold();
+			let removed = Relayers::<T>::mutate(|relayers| {
+				if relayers.len() as u32 <= threshold {
+					return false
+				}
+				relayers.retain(|r| *r != relayer);
+				true
+			});
+			if !removed {
+				Self::deposit_event(Event::ConflictingAttestation(relayer));
+				return
+			}
+
+			let account = RelayerAccounts::<T>::iter().find(|(_, r)| *r == relayer);
+			if let Some((account, _)) = account {
+				RelayerAccounts::<T>::remove(&account);
+			}
+			Self::deposit_event(Event::RelayerSlashed(relayer));
+		}
+
+		/// Identifies the receipt with index `receipt_index` in Ethereum block `block_hash`.
+		pub fn deposit_id(block_hash: H256, receipt_index: u64) -> H256 {
+			H256(sp_io::hashing::keccak_256(&(block_hash, receipt_index).encode()))
+		}
+
+		/// Total length of the trie nodes in a receipt proof.
+		pub fn proof_size(proof: &[Vec<u8>]) -> u32 {
+			proof.iter().map(|node| node.len() as u32).fold(0, u32::saturating_add)
+		}
+
+		/// Returns true if the deposit logged at `log_index` of receipt `deposit_id` has been
+		/// minted.
+		pub fn is_deposit_processed(deposit_id: H256, log_index: u64) -> bool {
+			ProcessedDeposits::<T>::contains_key(deposit_id, log_index)
+		}
+
+		/// Converts a native balance into the equivalent 18-decimal ERC20 amount.
+		pub fn to_erc20_amount(amount: T::Balance) -> U256 {
+			U256::from(amount.saturated_into::<u128>())
+				.saturating_mul(U256::exp10((ERC20_DECIMALS - NATIVE_DECIMALS) as usize))
+		}
+
+		/// Converts an ERC20 amount with `decimals` decimals into a 12-decimal balance.
+		///
+		/// Fails if the amount carries precision below one native unit or if the result does not
+		/// fit into `T::Balance`.
+		pub fn convert_amount(amount: U256, decimals: u8) -> Result<T::Balance, Error<T>> {
+			let decimals = decimals as u32;
+			let native = if decimals >= NATIVE_DECIMALS {
+				let divisor = U256::exp10((decimals - NATIVE_DECIMALS) as usize);
+				let (native, dust) = amount.div_mod(divisor);
+				ensure!(dust.is_zero(), Error::<T>::AmountHasDust);
+				native
+			} else {
+				amount
+					.checked_mul(U256::exp10((NATIVE_DECIMALS - decimals) as usize))
+					.ok_or(Error::<T>::AmountOverflow)?
+			};
+			ensure!(native <= U256::from(u128::MAX), Error::<T>::AmountOverflow);
+			T::Balance::try_from(native.low_u128()).map_err(|_| Error::<T>::AmountOverflow)
+		}
+	}
+}
+
+/// Resolves a signed origin to the Ethereum address registered for the signing relayer account
+/// in `RelayerAccounts`.
+pub struct EnsureRelayer<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> EnsureOrigin<T::Origin> for EnsureRelayer<T> {
+	type Success = H160;
+
+	fn try_origin(o: T::Origin) -> Result<Self::Success, T::Origin> {
+		Into::<Result<RawOrigin<T::AccountId>, T::Origin>>::into(o).and_then(|o| match o {
+			RawOrigin::Signed(who) => match RelayerAccounts::<T>::get(&who) {
+				Some(relayer) => Ok(relayer),
+				None => Err(T::Origin::from(RawOrigin::Signed(who))),
+			},
+			r => Err(T::Origin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> T::Origin {
+		let who = T::AccountId::default();
+		if !RelayerAccounts::<T>::contains_key(&who) {
+			RelayerAccounts::<T>::insert(&who, H160::repeat_byte(0xff));
+		}
+		T::Origin::from(RawOrigin::Signed(who))
+	}
+}
+
+impl<T: Config> EthereumHeaders for Pallet<T> {
+	fn receipts_root(block_hash: H256) -> Option<H256> {
+		ReceiptsRoots::<T>::get(block_hash)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn note_receipts_root(block_hash: H256, receipts_root: H256) {
+		ReceiptsRoots::<T>::insert(block_hash, receipts_root);
 	}
 }
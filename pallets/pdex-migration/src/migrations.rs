@@ -0,0 +1,76 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the PDEX migration pallet.
+
+/// Moves storage written by the `decl_storage!` version of the pallet, which lived under the
+/// fixed `NativePDEXMigration` prefix, to the prefix of the pallet's name in the runtime. That
+/// version only stored `Address`, so every other item starts out in its current layout.
+pub mod v1 {
+	use crate::{Config, Pallet};
+	use frame_support::{
+		storage::migration::move_pallet,
+		traits::{Get, PalletInfoAccess, StorageVersion},
+		weights::Weight,
+	};
+	#[cfg(feature = "try-runtime")]
+	use {crate::Address, frame_support::storage::migration::get_storage_value, sp_core::H160};
+
+	/// Storage prefix used by the `decl_storage!` version of the pallet.
+	pub const OLD_PREFIX: &[u8] = b"NativePDEXMigration";
+
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return 0
+		}
+
+		let new_prefix = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+		if new_prefix != OLD_PREFIX {
+			move_pallet(OLD_PREFIX, new_prefix);
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!(target: "runtime::pdex-migration", "migrated storage to v1");
+
+		// The number of moved keys is unknown, so we assume the whole block is used.
+		T::BlockWeights::get().max_block
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			return Ok(())
+		}
+		let address = get_storage_value::<H160>(OLD_PREFIX, b"Address", &[]).unwrap_or_default();
+		frame_support::storage::unhashed::put(b":pdex-migration:address", &address);
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		frame_support::ensure!(
+			StorageVersion::get::<Pallet<T>>() >= 1,
+			"storage version was not updated"
+		);
+		if let Some(address) =
+			frame_support::storage::unhashed::take::<H160>(b":pdex-migration:address")
+		{
+			frame_support::ensure!(Address::<T>::get() == address, "Address was not migrated");
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,197 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mock runtime for erc20-pdex-migration-pallet tests
+
+use crate as erc20_pdex_migration_pallet;
+use crate::EthereumHeaders;
+use frame_support::{
+	ord_parameter_types, parameter_types,
+	traits::{Contains, Everything, GenesisBuild, Nothing},
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use orml_traits::parameter_type_with_key;
+use polkadex_primitives::assets::AssetId;
+use sp_core::{H160, H256};
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use std::{cell::RefCell, collections::BTreeMap};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Balance = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const GUARDIAN: AccountId = 100;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+		PDEXMigration: erc20_pdex_migration_pallet::{Pallet, Call, Storage, Config<T>, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+parameter_types! {
+	pub const MaxLocks: u32 = 50;
+}
+
+impl orml_tokens::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = MaxLocks;
+	type DustRemovalWhitelist = Nothing;
+}
+
+thread_local! {
+	static RECEIPTS_ROOTS: RefCell<BTreeMap<H256, H256>> = RefCell::new(BTreeMap::new());
+}
+
+/// Ethereum receipts roots known to the tests, kept outside of storage.
+pub struct KnownHeaders;
+
+impl KnownHeaders {
+	pub fn insert(block_hash: H256, receipts_root: H256) {
+		RECEIPTS_ROOTS.with(|roots| roots.borrow_mut().insert(block_hash, receipts_root));
+	}
+}
+
+impl EthereumHeaders for KnownHeaders {
+	fn receipts_root(block_hash: H256) -> Option<H256> {
+		RECEIPTS_ROOTS.with(|roots| roots.borrow().get(&block_hash).copied())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn note_receipts_root(block_hash: H256, receipts_root: H256) {
+		Self::insert(block_hash, receipts_root)
+	}
+}
+
+/// Native PDEX and `AssetId::Asset(1)` are the only active assets.
+pub struct ActiveAssets;
+
+impl Contains<AssetId> for ActiveAssets {
+	fn contains(asset_id: &AssetId) -> bool {
+		matches!(asset_id, AssetId::POLKADEX | AssetId::Asset(1))
+	}
+}
+
+parameter_types! {
+	pub const MaxRelayers: u32 = 5;
+	pub const ProposalLifetime: u64 = 10;
+}
+
+ord_parameter_types! {
+	pub const Guardian: AccountId = GUARDIAN;
+}
+
+impl erc20_pdex_migration_pallet::Config for Test {
+	type Event = Event;
+	type Balance = Balance;
+	type Currency = Tokens;
+	type CallOrigin = erc20_pdex_migration_pallet::EnsureRelayer<Test>;
+	type GovernanceOrigin = EnsureRoot<AccountId>;
+	type GuardianOrigin = EnsureSignedBy<Guardian, AccountId>;
+	type EthereumHeaders = KnownHeaders;
+	type ActiveAssets = ActiveAssets;
+	type MaxRelayers = MaxRelayers;
+	type ProposalLifetime = ProposalLifetime;
+	type WeightInfo = ();
+}
+
+/// Ethereum address registered for the relayer signing as `who`.
+pub fn relayer(who: AccountId) -> H160 {
+	H160::from_low_u64_be(who)
+}
+
+/// Address of the migration contract on Ethereum.
+pub fn contract() -> H160 {
+	H160::repeat_byte(0xaa)
+}
+
+/// Address of the ERC20 PDEX token on Ethereum.
+pub fn pdex_token() -> H160 {
+	H160::repeat_byte(0xdd)
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	erc20_pdex_migration_pallet::GenesisConfig::<Test> {
+		address: contract(),
+		relayers: [10, 11, 12].iter().map(|who| (*who, relayer(*who))).collect(),
+		threshold: 2,
+		migration_caps: vec![],
+		tokens: vec![(pdex_token(), AssetId::POLKADEX, 18)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,1002 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	ethereum::{self, fixtures},
+	migrations,
+	mock::*,
+	Address, Error, EthereumHeaders, PeriodLimit, TokenInfo, TotalMinted, Withdrawal,
+	WithdrawalStatus,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::migration::put_storage_value,
+	traits::{GenesisBuild, OnInitialize, StorageVersion},
+};
+use orml_traits::MultiCurrency;
+use polkadex_primitives::assets::AssetId;
+use sp_core::{H160, H256, U256};
+use sp_runtime::{DispatchError, DispatchResult};
+
+const UNIT: Balance = 1_000_000_000_000;
+
+fn erc20(native: Balance) -> U256 {
+	U256::from(native) * U256::exp10(6)
+}
+
+fn block_hash(n: u64) -> H256 {
+	H256::from_low_u64_be(n)
+}
+
+/// Deposit id of the only receipt in Ethereum block `n`.
+fn deposit_id(n: u64) -> H256 {
+	PDEXMigration::deposit_id(block_hash(n), 0)
+}
+
+fn sender() -> H160 {
+	H160::repeat_byte(0xbb)
+}
+
+/// Makes block `n` known with a single receipt locking `amount` of `token` for `recipient` and
+/// returns the proof of that receipt.
+fn deposit_proof(
+	n: u64,
+	contract: H160,
+	token: H160,
+	recipient: AccountId,
+	amount: U256,
+) -> Vec<Vec<u8>> {
+	let receipt = fixtures::locked_receipt(contract, token, sender(), &recipient.encode(), amount);
+	let (receipts_root, proof) = fixtures::single_receipt_proof(0, &receipt);
+	KnownHeaders::insert(block_hash(n), receipts_root);
+	proof
+}
+
+fn attest(relayer: AccountId, n: u64, recipient: AccountId, amount: U256) -> DispatchResult {
+	let proof = deposit_proof(n, contract(), pdex_token(), recipient, amount);
+	PDEXMigration::mint(Origin::signed(relayer), block_hash(n), 0, proof, 0)
+}
+
+fn mint_with_quorum(tx: u64, recipient: AccountId, amount: U256) -> DispatchResult {
+	attest(10, tx, recipient, amount)?;
+	attest(11, tx, recipient, amount)
+}
+
+fn free_balance(who: AccountId) -> Balance {
+	<Tokens as MultiCurrency<AccountId>>::free_balance(AssetId::POLKADEX, &who)
+}
+
+fn last_event() -> Event {
+	System::events().pop().expect("an event was deposited").event
+}
+
+fn has_event(event: crate::Event<Test>) -> bool {
+	System::events()
+		.iter()
+		.any(|record| record.event == Event::PDEXMigration(event.clone()))
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		PDEXMigration::on_initialize(next);
+	}
+}
+
+#[test]
+fn convert_amount_scales_to_native_decimals() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(PDEXMigration::convert_amount(erc20(UNIT), 18), Ok(UNIT));
+		assert_eq!(PDEXMigration::convert_amount(U256::zero(), 18), Ok(0));
+		assert_eq!(
+			PDEXMigration::convert_amount(erc20(UNIT) + 1, 18),
+			Err(Error::<Test>::AmountHasDust)
+		);
+		assert_eq!(
+			PDEXMigration::convert_amount(erc20(Balance::MAX) + erc20(1), 18),
+			Err(Error::<Test>::AmountOverflow)
+		);
+		assert_eq!(PDEXMigration::convert_amount(U256::from(1_000_000u64), 6), Ok(UNIT));
+		assert_eq!(PDEXMigration::convert_amount(U256::from(UNIT), 12), Ok(UNIT));
+		assert_eq!(PDEXMigration::convert_amount(U256::MAX, 6), Err(Error::<Test>::AmountOverflow));
+		assert_eq!(
+			PDEXMigration::convert_amount(U256::from(Balance::MAX), 6),
+			Err(Error::<Test>::AmountOverflow)
+		);
+		assert_eq!(PDEXMigration::to_erc20_amount(UNIT), erc20(UNIT));
+	});
+}
+
+#[test]
+fn mint_credits_recipient_once_threshold_is_reached() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(attest(10, 1, ALICE, erc20(UNIT)));
+		assert_eq!(free_balance(ALICE), 0);
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::MintAttested(deposit_id(1), 0, relayer(10), 1))
+		);
+
+		assert_ok!(attest(11, 1, ALICE, erc20(UNIT)));
+		assert_eq!(free_balance(ALICE), UNIT);
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::NativePDEXMinted(
+				deposit_id(1),
+				0,
+				pdex_token(),
+				sender(),
+				ALICE,
+				erc20(UNIT),
+				UNIT
+			))
+		);
+		assert!(PDEXMigration::is_deposit_processed(deposit_id(1), 0));
+		assert_eq!(PDEXMigration::processed_deposits(deposit_id(1), 0), Some(1));
+		assert!(PDEXMigration::proposals(deposit_id(1), 0).is_none());
+		assert_eq!(PDEXMigration::total_minted(AssetId::POLKADEX), UNIT);
+		assert_eq!(PDEXMigration::minted_per_recipient(AssetId::POLKADEX, ALICE), UNIT);
+	});
+}
+
+#[test]
+fn mint_rejects_invalid_amounts() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(attest(10, 1, ALICE, erc20(UNIT) + 1), Error::<Test>::AmountHasDust);
+		assert_noop!(
+			attest(10, 1, ALICE, erc20(Balance::MAX) + erc20(1)),
+			Error::<Test>::AmountOverflow
+		);
+	});
+}
+
+#[test]
+fn mint_rejects_overflowing_total() {
+	new_test_ext().execute_with(|| {
+		TotalMinted::<Test>::insert(AssetId::POLKADEX, Balance::MAX);
+		assert_ok!(attest(10, 1, ALICE, erc20(UNIT)));
+		assert_noop!(attest(11, 1, ALICE, erc20(UNIT)), Error::<Test>::AmountOverflow);
+	});
+}
+
+#[test]
+fn mint_rejects_processed_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(mint_with_quorum(1, ALICE, erc20(UNIT)));
+		assert_noop!(attest(12, 1, ALICE, erc20(UNIT)), Error::<Test>::AlreadyProcessed);
+	});
+}
+
+#[test]
+fn mint_requires_registered_relayer() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(attest(99, 1, ALICE, erc20(UNIT)), DispatchError::BadOrigin);
+		let proof = deposit_proof(1, contract(), pdex_token(), ALICE, erc20(UNIT));
+		assert_noop!(
+			PDEXMigration::mint(Origin::root(), block_hash(1), 0, proof, 0),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn mint_rejects_unknown_block() {
+	new_test_ext().execute_with(|| {
+		let proof = deposit_proof(1, contract(), pdex_token(), ALICE, erc20(UNIT));
+		assert_noop!(
+			PDEXMigration::mint(Origin::signed(10), block_hash(2), 0, proof, 0),
+			Error::<Test>::UnknownBlock
+		);
+	});
+}
+
+#[test]
+fn mint_rejects_invalid_proof() {
+	new_test_ext().execute_with(|| {
+		let proof = deposit_proof(1, contract(), pdex_token(), ALICE, erc20(UNIT));
+		assert_noop!(
+			PDEXMigration::mint(Origin::signed(10), block_hash(1), 1, proof.clone(), 0),
+			Error::<Test>::InvalidProof
+		);
+
+		let mut tampered = proof;
+		let last = tampered[0].len() - 1;
+		tampered[0][last] ^= 1;
+		assert_noop!(
+			PDEXMigration::mint(Origin::signed(10), block_hash(1), 0, tampered, 0),
+			Error::<Test>::InvalidProof
+		);
+		assert_noop!(
+			PDEXMigration::mint(Origin::signed(10), block_hash(1), 0, vec![], 0),
+			Error::<Test>::InvalidProof
+		);
+	});
+}
+
+#[test]
+fn mint_rejects_unknown_log() {
+	new_test_ext().execute_with(|| {
+		let proof = deposit_proof(1, contract(), pdex_token(), ALICE, erc20(UNIT));
+		assert_noop!(
+			PDEXMigration::mint(Origin::signed(10), block_hash(1), 0, proof, 1),
+			Error::<Test>::InvalidPayload
+		);
+
+		let proof = deposit_proof(2, H160::repeat_byte(0xcc), pdex_token(), ALICE, erc20(UNIT));
+		assert_noop!(
+			PDEXMigration::mint(Origin::signed(10), block_hash(2), 0, proof, 0),
+			Error::<Test>::InvalidContract
+		);
+	});
+}
+
+#[test]
+fn receipt_proofs_follow_branch_nodes() {
+	let receipt = |recipient: AccountId| {
+		fixtures::locked_receipt(
+			contract(),
+			pdex_token(),
+			sender(),
+			&recipient.encode(),
+			erc20(UNIT),
+		)
+	};
+	// Keys are the RLP encoded receipt indices: 0x80 for the first and 0x01 for the second
+	// receipt. The root branches on the first nibble and leaves hold the second one.
+	let first = fixtures::leaf(&[0], &receipt(ALICE));
+	let mut typed = vec![0x02];
+	typed.extend(receipt(BOB));
+	let second = fixtures::leaf(&[1], &typed);
+
+	let mut branch = rlp::RlpStream::new_list(17);
+	for nibble in 0..16 {
+		match nibble {
+			0 => branch.append(&sp_io::hashing::keccak_256(&second).to_vec()),
+			8 => branch.append(&sp_io::hashing::keccak_256(&first).to_vec()),
+			_ => branch.append_empty_data(),
+		};
+	}
+	branch.append_empty_data();
+	let branch = branch.out().to_vec();
+	let root = H256(sp_io::hashing::keccak_256(&branch));
+
+	let proven = ethereum::verify_receipt_proof(root, 0, &[branch.clone(), first.clone()]);
+	assert_eq!(proven, Some(receipt(ALICE)));
+	let proven = ethereum::verify_receipt_proof(root, 1, &[branch.clone(), second.clone()])
+		.expect("second receipt is in the trie");
+	let log = ethereum::receipt_log(&proven, 0).expect("typed receipts are decoded");
+	assert_eq!(log.address, contract());
+	assert_eq!(log.decode_locked::<AccountId>(), Some((pdex_token(), sender(), BOB, erc20(UNIT))));
+
+	assert_eq!(ethereum::verify_receipt_proof(root, 1, &[branch.clone(), first]), None);
+	assert_eq!(ethereum::verify_receipt_proof(root, 2, &[branch, second]), None);
+}
+
+#[test]
+fn relayers_agree_on_receipts_roots() {
+	new_test_ext().execute_with(|| {
+		let root = H256::repeat_byte(9);
+		let submit = |who: AccountId, root: H256| {
+			PDEXMigration::submit_receipts_root(Origin::signed(who), block_hash(1), root)
+		};
+		assert_noop!(submit(ALICE, root), DispatchError::BadOrigin);
+		assert_noop!(
+			PDEXMigration::submit_receipts_root(Origin::root(), block_hash(1), root),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(submit(12, H256::repeat_byte(8)));
+		assert_ok!(submit(10, root));
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::ReceiptsRootAttested(
+				block_hash(1),
+				root,
+				relayer(10),
+				1
+			))
+		);
+		assert_noop!(submit(10, root), Error::<Test>::AlreadyAttested);
+		assert_eq!(<PDEXMigration as EthereumHeaders>::receipts_root(block_hash(1)), None);
+
+		assert_ok!(submit(11, root));
+		assert_eq!(<PDEXMigration as EthereumHeaders>::receipts_root(block_hash(1)), Some(root));
+		assert!(has_event(crate::Event::ReceiptsRootSubmitted(block_hash(1), root)));
+		assert!(PDEXMigration::receipts_root_votes(block_hash(1)).is_empty());
+
+		// The relayer that reported a different root is slashed.
+		assert_eq!(last_event(), Event::PDEXMigration(crate::Event::RelayerSlashed(relayer(12))));
+		assert_eq!(PDEXMigration::relayers(), vec![relayer(10), relayer(11)]);
+		assert_eq!(PDEXMigration::relayer_accounts(12), None);
+
+		// An agreed root can not be overwritten.
+		assert_noop!(submit(10, root), Error::<Test>::ReceiptsRootAlreadyKnown);
+	});
+}
+
+#[test]
+fn late_conflicting_receipts_roots_slash_relayers() {
+	new_test_ext().execute_with(|| {
+		let root = H256::repeat_byte(9);
+		let submit = |who: AccountId, root: H256| {
+			PDEXMigration::submit_receipts_root(Origin::signed(who), block_hash(1), root)
+		};
+		assert_ok!(submit(10, root));
+		assert_ok!(submit(11, root));
+		assert_noop!(submit(12, root), Error::<Test>::ReceiptsRootAlreadyKnown);
+
+		assert_ok!(submit(12, H256::repeat_byte(8)));
+		assert_eq!(last_event(), Event::PDEXMigration(crate::Event::RelayerSlashed(relayer(12))));
+		assert_eq!(PDEXMigration::relayers(), vec![relayer(10), relayer(11)]);
+		assert_eq!(<PDEXMigration as EthereumHeaders>::receipts_root(block_hash(1)), Some(root));
+
+		// The last relayers needed to reach the threshold are kept.
+		assert_ok!(submit(11, H256::repeat_byte(8)));
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::ConflictingAttestation(relayer(11)))
+		);
+		assert_eq!(PDEXMigration::relayers(), vec![relayer(10), relayer(11)]);
+	});
+}
+
+#[test]
+fn votes_of_removed_relayers_are_not_counted() {
+	new_test_ext().execute_with(|| {
+		let root = H256::repeat_byte(9);
+		assert_ok!(PDEXMigration::submit_receipts_root(Origin::signed(12), block_hash(1), root));
+		assert_ok!(attest(12, 2, ALICE, erc20(UNIT)));
+		assert_ok!(PDEXMigration::remove_relayer(Origin::root(), 12));
+
+		assert_ok!(PDEXMigration::submit_receipts_root(Origin::signed(10), block_hash(1), root));
+		assert_eq!(<PDEXMigration as EthereumHeaders>::receipts_root(block_hash(1)), None);
+		assert_eq!(PDEXMigration::receipts_root_votes(block_hash(1)), vec![(relayer(10), root)]);
+
+		assert_ok!(attest(10, 2, ALICE, erc20(UNIT)));
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::MintAttested(deposit_id(2), 0, relayer(10), 1))
+		);
+		assert_eq!(free_balance(ALICE), 0);
+
+		assert_ok!(attest(11, 2, ALICE, erc20(UNIT)));
+		assert_eq!(free_balance(ALICE), UNIT);
+	});
+}
+
+#[test]
+fn registered_tokens_are_minted_as_their_asset() {
+	new_test_ext().execute_with(|| {
+		let usdt = H160::repeat_byte(0xee);
+		assert_ok!(PDEXMigration::register_token(Origin::root(), usdt, AssetId::Asset(1), 6));
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::TokenRegistered(usdt, AssetId::Asset(1), 6))
+		);
+		assert_ok!(PDEXMigration::set_migration_cap(Origin::root(), AssetId::POLKADEX, Some(0)));
+		assert_ok!(PDEXMigration::set_migration_cap(
+			Origin::root(),
+			AssetId::Asset(1),
+			Some(5 * UNIT)
+		));
+
+		let amount = U256::from(5_000_000u64);
+		for relayer in [10, 11] {
+			let proof = deposit_proof(1, contract(), usdt, ALICE, amount);
+			assert_ok!(PDEXMigration::mint(Origin::signed(relayer), block_hash(1), 0, proof, 0));
+		}
+		assert_eq!(
+			<Tokens as MultiCurrency<AccountId>>::free_balance(AssetId::Asset(1), &ALICE),
+			5 * UNIT
+		);
+		assert_eq!(free_balance(ALICE), 0);
+		assert_eq!(PDEXMigration::total_minted(AssetId::POLKADEX), 0);
+		assert_eq!(PDEXMigration::total_minted(AssetId::Asset(1)), 5 * UNIT);
+		assert_eq!(PDEXMigration::minted_per_recipient(AssetId::Asset(1), ALICE), 5 * UNIT);
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::ERC20Minted(
+				deposit_id(1),
+				0,
+				usdt,
+				AssetId::Asset(1),
+				ALICE,
+				amount,
+				5 * UNIT
+			))
+		);
+		assert!(PDEXMigration::is_deposit_processed(deposit_id(1), 0));
+
+		// The cap of the asset is exhausted.
+		let proof = deposit_proof(2, contract(), usdt, ALICE, U256::from(1u64));
+		assert_ok!(PDEXMigration::mint(Origin::signed(10), block_hash(2), 0, proof.clone(), 0));
+		assert_noop!(
+			PDEXMigration::mint(Origin::signed(11), block_hash(2), 0, proof, 0),
+			Error::<Test>::MigrationCapExceeded
+		);
+	});
+}
+
+#[test]
+fn registered_tokens_are_delayed() {
+	new_test_ext().execute_with(|| {
+		let usdt = H160::repeat_byte(0xee);
+		assert_ok!(PDEXMigration::register_token(Origin::root(), usdt, AssetId::Asset(1), 6));
+		assert_ok!(PDEXMigration::set_mint_delay(Origin::root(), AssetId::Asset(1), Some(UNIT), 5));
+
+		let amount = U256::from(2_000_000u64);
+		for relayer in [10, 11] {
+			let proof = deposit_proof(1, contract(), usdt, ALICE, amount);
+			assert_ok!(PDEXMigration::mint(Origin::signed(relayer), block_hash(1), 0, proof, 0));
+		}
+		let asset_balance =
+			|| <Tokens as MultiCurrency<AccountId>>::free_balance(AssetId::Asset(1), &ALICE);
+		assert_eq!(asset_balance(), 0);
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::MintQueued(
+				deposit_id(1),
+				0,
+				AssetId::Asset(1),
+				ALICE,
+				2 * UNIT,
+				6
+			))
+		);
+
+		run_to_block(6);
+		assert_eq!(asset_balance(), 2 * UNIT);
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::ERC20Minted(
+				deposit_id(1),
+				0,
+				usdt,
+				AssetId::Asset(1),
+				ALICE,
+				amount,
+				2 * UNIT
+			))
+		);
+	});
+}
+
+#[test]
+fn mint_rejects_unregistered_token() {
+	new_test_ext().execute_with(|| {
+		let proof = deposit_proof(1, contract(), H160::repeat_byte(0xee), ALICE, erc20(UNIT));
+		assert_noop!(
+			PDEXMigration::mint(Origin::signed(10), block_hash(1), 0, proof.clone(), 0),
+			Error::<Test>::TokenNotRegistered
+		);
+
+		assert_ok!(PDEXMigration::deregister_token(Origin::root(), pdex_token()));
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::TokenDeregistered(pdex_token()))
+		);
+		assert_noop!(attest(10, 2, ALICE, erc20(UNIT)), Error::<Test>::TokenNotRegistered);
+	});
+}
+
+#[test]
+fn token_registry_is_managed_by_governance() {
+	new_test_ext().execute_with(|| {
+		let token = H160::repeat_byte(0xee);
+		assert_noop!(
+			PDEXMigration::register_token(Origin::signed(ALICE), token, AssetId::Asset(1), 6),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PDEXMigration::register_token(Origin::root(), pdex_token(), AssetId::Asset(1), 6),
+			Error::<Test>::TokenAlreadyRegistered
+		);
+		assert_noop!(
+			PDEXMigration::register_token(Origin::root(), token, AssetId::Asset(1), 37),
+			Error::<Test>::InvalidDecimals
+		);
+		assert_noop!(
+			PDEXMigration::register_token(Origin::root(), token, AssetId::Asset(2), 6),
+			Error::<Test>::AssetNotActive
+		);
+		assert_ok!(PDEXMigration::register_token(Origin::root(), token, AssetId::Asset(1), 36));
+		assert_eq!(
+			PDEXMigration::registered_tokens(token),
+			Some(TokenInfo { asset_id: AssetId::Asset(1), decimals: 36 })
+		);
+
+		assert_noop!(
+			PDEXMigration::deregister_token(Origin::signed(ALICE), token),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PDEXMigration::deregister_token(Origin::root(), token));
+		assert_noop!(
+			PDEXMigration::deregister_token(Origin::root(), token),
+			Error::<Test>::TokenNotRegistered
+		);
+	});
+}
+
+#[test]
+fn mint_rejects_second_attestation_from_same_relayer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(attest(10, 1, ALICE, erc20(UNIT)));
+		assert_noop!(attest(10, 1, ALICE, erc20(UNIT)), Error::<Test>::AlreadyAttested);
+		assert_noop!(attest(10, 1, BOB, erc20(UNIT)), Error::<Test>::AlreadyAttested);
+	});
+}
+
+#[test]
+fn conflicting_attestations_are_not_counted() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(attest(12, 1, BOB, erc20(UNIT)));
+		assert_ok!(attest(10, 1, ALICE, erc20(UNIT)));
+		assert_eq!(free_balance(ALICE), 0);
+
+		assert_ok!(attest(11, 1, ALICE, erc20(UNIT)));
+		assert_eq!(free_balance(ALICE), UNIT);
+		assert_eq!(free_balance(BOB), 0);
+
+		assert!(has_event(crate::Event::RelayerSlashed(relayer(12))));
+		assert_eq!(PDEXMigration::relayers(), vec![relayer(10), relayer(11)]);
+		assert_eq!(PDEXMigration::relayer_accounts(12), None);
+	});
+}
+
+#[test]
+fn stale_proposals_expire() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(attest(10, 1, ALICE, erc20(UNIT)));
+		let expiry = 1 + ProposalLifetime::get();
+		assert_eq!(PDEXMigration::proposals(deposit_id(1), 0).map(|p| p.expiry), Some(expiry));
+
+		run_to_block(expiry);
+		assert!(PDEXMigration::proposals(deposit_id(1), 0).is_none());
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::ProposalExpired(deposit_id(1), 0))
+		);
+
+		// A new round of attestations starts from scratch.
+		assert_ok!(attest(10, 1, ALICE, erc20(UNIT)));
+		assert_eq!(free_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn governance_manages_relayers() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PDEXMigration::add_relayer(Origin::signed(ALICE), 13, relayer(13)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PDEXMigration::add_relayer(Origin::root(), 13, relayer(13)));
+		assert_eq!(last_event(), Event::PDEXMigration(crate::Event::RelayerAdded(13, relayer(13))));
+		assert_eq!(PDEXMigration::relayer_accounts(13), Some(relayer(13)));
+		assert_noop!(
+			PDEXMigration::add_relayer(Origin::root(), 13, relayer(14)),
+			Error::<Test>::RelayerAlreadyExists
+		);
+		assert_noop!(
+			PDEXMigration::add_relayer(Origin::root(), 14, relayer(13)),
+			Error::<Test>::RelayerAlreadyExists
+		);
+		assert_ok!(PDEXMigration::add_relayer(Origin::root(), 14, relayer(14)));
+		assert_noop!(
+			PDEXMigration::add_relayer(Origin::root(), 15, relayer(15)),
+			Error::<Test>::TooManyRelayers
+		);
+
+		assert_noop!(
+			PDEXMigration::remove_relayer(Origin::signed(ALICE), 13),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PDEXMigration::remove_relayer(Origin::root(), 15),
+			Error::<Test>::RelayerNotFound
+		);
+		assert_ok!(PDEXMigration::remove_relayer(Origin::root(), 13));
+		assert_ok!(PDEXMigration::remove_relayer(Origin::root(), 14));
+		assert_ok!(PDEXMigration::remove_relayer(Origin::root(), 12));
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::RelayerRemoved(12, relayer(12)))
+		);
+		assert_eq!(PDEXMigration::relayer_accounts(12), None);
+		assert_eq!(PDEXMigration::relayers(), vec![relayer(10), relayer(11)]);
+		assert_noop!(attest(12, 1, ALICE, erc20(UNIT)), DispatchError::BadOrigin);
+		assert_noop!(
+			PDEXMigration::remove_relayer(Origin::root(), 11),
+			Error::<Test>::InvalidThreshold
+		);
+	});
+}
+
+#[test]
+fn governance_sets_threshold() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PDEXMigration::set_threshold(Origin::signed(ALICE), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PDEXMigration::set_threshold(Origin::root(), 0),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			PDEXMigration::set_threshold(Origin::root(), 4),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_ok!(PDEXMigration::set_threshold(Origin::root(), 3));
+		assert_eq!(PDEXMigration::threshold(), 3);
+		assert_eq!(last_event(), Event::PDEXMigration(crate::Event::ThresholdChanged(3)));
+	});
+}
+
+#[test]
+fn migration_cap_is_enforced() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PDEXMigration::set_migration_cap(Origin::signed(ALICE), AssetId::POLKADEX, Some(UNIT)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PDEXMigration::set_migration_cap(Origin::root(), AssetId::POLKADEX, Some(UNIT)));
+		assert_ok!(mint_with_quorum(1, ALICE, erc20(UNIT)));
+		assert_ok!(attest(10, 2, ALICE, erc20(1)));
+		assert_noop!(attest(11, 2, ALICE, erc20(1)), Error::<Test>::MigrationCapExceeded);
+
+		assert_ok!(PDEXMigration::set_migration_cap(Origin::root(), AssetId::POLKADEX, None));
+		assert_ok!(attest(11, 2, ALICE, erc20(1)));
+		assert_eq!(PDEXMigration::total_minted(AssetId::POLKADEX), UNIT + 1);
+	});
+}
+
+#[test]
+fn period_limit_is_enforced() {
+	new_test_ext().execute_with(|| {
+		let limit = PeriodLimit { period: 10, limit: UNIT };
+		assert_noop!(
+			PDEXMigration::set_mint_period_limit(
+				Origin::signed(ALICE),
+				AssetId::POLKADEX,
+				Some(limit)
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PDEXMigration::set_mint_period_limit(
+				Origin::root(),
+				AssetId::POLKADEX,
+				Some(PeriodLimit { period: 0, limit: UNIT })
+			),
+			Error::<Test>::InvalidPeriod
+		);
+		assert_ok!(PDEXMigration::set_mint_period_limit(
+			Origin::root(),
+			AssetId::POLKADEX,
+			Some(limit)
+		));
+
+		assert_ok!(mint_with_quorum(1, ALICE, erc20(UNIT)));
+		assert_eq!(PDEXMigration::current_period(AssetId::POLKADEX), (1, UNIT));
+		run_to_block(5);
+		assert_ok!(attest(10, 2, BOB, erc20(1)));
+		assert_noop!(attest(11, 2, BOB, erc20(1)), Error::<Test>::PeriodLimitExceeded);
+
+		run_to_block(11);
+		assert_ok!(mint_with_quorum(3, BOB, erc20(UNIT)));
+		assert_eq!(PDEXMigration::current_period(AssetId::POLKADEX), (11, UNIT));
+	});
+}
+
+#[test]
+fn recipient_limit_is_enforced() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PDEXMigration::set_recipient_limit(
+				Origin::signed(ALICE),
+				AssetId::POLKADEX,
+				Some(UNIT)
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PDEXMigration::set_recipient_limit(
+			Origin::root(),
+			AssetId::POLKADEX,
+			Some(UNIT)
+		));
+		assert_ok!(mint_with_quorum(1, ALICE, erc20(UNIT)));
+		assert_ok!(attest(10, 2, ALICE, erc20(1)));
+		assert_noop!(attest(11, 2, ALICE, erc20(1)), Error::<Test>::RecipientLimitExceeded);
+		assert_ok!(mint_with_quorum(3, BOB, erc20(UNIT)));
+		assert_eq!(PDEXMigration::minted_per_recipient(AssetId::POLKADEX, BOB), UNIT);
+	});
+}
+
+#[test]
+fn burn_for_erc20_records_withdrawal() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Tokens::deposit(AssetId::POLKADEX, &ALICE, 3 * UNIT));
+		assert_noop!(
+			PDEXMigration::burn_for_erc20(Origin::signed(ALICE), 0, H160::repeat_byte(1)),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			PDEXMigration::burn_for_erc20(Origin::signed(ALICE), 4 * UNIT, H160::repeat_byte(1)),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+		assert_noop!(
+			PDEXMigration::burn_for_erc20(Origin::root(), UNIT, H160::repeat_byte(1)),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(PDEXMigration::burn_for_erc20(
+			Origin::signed(ALICE),
+			UNIT,
+			H160::repeat_byte(1)
+		));
+		assert_ok!(PDEXMigration::burn_for_erc20(
+			Origin::signed(ALICE),
+			UNIT,
+			H160::repeat_byte(2)
+		));
+		assert_eq!(free_balance(ALICE), UNIT);
+		assert_eq!(PDEXMigration::withdrawal_nonce(), 2);
+		assert_eq!(
+			PDEXMigration::withdrawals(1),
+			Some(Withdrawal {
+				who: ALICE,
+				eth_recipient: H160::repeat_byte(2),
+				amount: UNIT,
+				erc20_amount: erc20(UNIT),
+				requested_at: 1,
+				confirmations: vec![],
+				status: WithdrawalStatus::Pending,
+			})
+		);
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::WithdrawalRequested(
+				1,
+				ALICE,
+				H160::repeat_byte(2),
+				UNIT,
+				erc20(UNIT)
+			))
+		);
+	});
+}
+
+#[test]
+fn relayers_confirm_withdrawal_release() {
+	new_test_ext().execute_with(|| {
+		let release = H256::repeat_byte(7);
+		assert_ok!(Tokens::deposit(AssetId::POLKADEX, &ALICE, UNIT));
+		assert_ok!(PDEXMigration::burn_for_erc20(
+			Origin::signed(ALICE),
+			UNIT,
+			H160::repeat_byte(1)
+		));
+
+		assert_noop!(
+			PDEXMigration::confirm_withdrawal(Origin::signed(10), 1, release),
+			Error::<Test>::WithdrawalNotFound
+		);
+		assert_noop!(
+			PDEXMigration::confirm_withdrawal(Origin::signed(99), 0, release),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(PDEXMigration::confirm_withdrawal(Origin::signed(10), 0, release));
+		assert_noop!(
+			PDEXMigration::confirm_withdrawal(Origin::signed(10), 0, release),
+			Error::<Test>::AlreadyConfirmed
+		);
+		assert_eq!(
+			PDEXMigration::withdrawals(0).map(|w| w.status),
+			Some(WithdrawalStatus::Pending)
+		);
+
+		assert_ok!(PDEXMigration::confirm_withdrawal(Origin::signed(11), 0, release));
+		assert_eq!(
+			PDEXMigration::withdrawals(0).map(|w| w.status),
+			Some(WithdrawalStatus::Released(release))
+		);
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::WithdrawalReleased(0, release))
+		);
+		assert_noop!(
+			PDEXMigration::confirm_withdrawal(Origin::signed(12), 0, release),
+			Error::<Test>::WithdrawalAlreadyReleased
+		);
+	});
+}
+
+#[test]
+fn large_mints_are_delayed() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PDEXMigration::set_mint_delay(
+				Origin::signed(GUARDIAN),
+				AssetId::POLKADEX,
+				Some(UNIT),
+				5
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PDEXMigration::set_mint_delay(Origin::root(), AssetId::POLKADEX, Some(UNIT), 5));
+
+		assert_ok!(mint_with_quorum(1, ALICE, erc20(UNIT)));
+		assert_eq!(free_balance(ALICE), UNIT);
+
+		assert_ok!(mint_with_quorum(2, BOB, erc20(2 * UNIT)));
+		assert_eq!(free_balance(BOB), 0);
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::MintQueued(
+				deposit_id(2),
+				0,
+				AssetId::POLKADEX,
+				BOB,
+				2 * UNIT,
+				6
+			))
+		);
+		assert_noop!(attest(12, 2, BOB, erc20(2 * UNIT)), Error::<Test>::AlreadyProcessed);
+
+		run_to_block(5);
+		assert_eq!(free_balance(BOB), 0);
+		run_to_block(6);
+		assert_eq!(free_balance(BOB), 2 * UNIT);
+		assert!(PDEXMigration::queued_mints(deposit_id(2), 0).is_none());
+	});
+}
+
+#[test]
+fn guardian_cancels_queued_mint() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PDEXMigration::set_mint_delay(Origin::root(), AssetId::POLKADEX, Some(0), 5));
+		assert_ok!(mint_with_quorum(1, ALICE, erc20(UNIT)));
+		assert_eq!(PDEXMigration::total_minted(AssetId::POLKADEX), UNIT);
+
+		assert_noop!(
+			PDEXMigration::cancel_mint(Origin::signed(ALICE), deposit_id(1), 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PDEXMigration::cancel_mint(Origin::signed(GUARDIAN), deposit_id(2), 0),
+			Error::<Test>::QueuedMintNotFound
+		);
+		assert_ok!(PDEXMigration::cancel_mint(Origin::signed(GUARDIAN), deposit_id(1), 0));
+		assert_eq!(
+			last_event(),
+			Event::PDEXMigration(crate::Event::MintCancelled(deposit_id(1), 0))
+		);
+		assert_eq!(PDEXMigration::total_minted(AssetId::POLKADEX), 0);
+		assert_eq!(PDEXMigration::minted_per_recipient(AssetId::POLKADEX, ALICE), 0);
+
+		run_to_block(10);
+		assert_eq!(free_balance(ALICE), 0);
+		assert!(PDEXMigration::is_deposit_processed(deposit_id(1), 0));
+	});
+}
+
+#[test]
+fn cancelled_mint_frees_period_limit() {
+	new_test_ext().execute_with(|| {
+		let limit = PeriodLimit { period: 10, limit: UNIT };
+		assert_ok!(PDEXMigration::set_mint_period_limit(
+			Origin::root(),
+			AssetId::POLKADEX,
+			Some(limit)
+		));
+		assert_ok!(PDEXMigration::set_mint_delay(Origin::root(), AssetId::POLKADEX, Some(0), 20));
+		assert_ok!(mint_with_quorum(1, ALICE, erc20(UNIT)));
+		assert_eq!(PDEXMigration::current_period(AssetId::POLKADEX), (1, UNIT));
+
+		assert_ok!(PDEXMigration::cancel_mint(Origin::signed(GUARDIAN), deposit_id(1), 0));
+		assert_eq!(PDEXMigration::current_period(AssetId::POLKADEX), (1, 0));
+		assert_ok!(mint_with_quorum(2, BOB, erc20(UNIT)));
+
+		// A mint queued in an earlier period does not free the current one.
+		run_to_block(11);
+		assert_ok!(mint_with_quorum(3, BOB, erc20(1)));
+		assert_eq!(PDEXMigration::current_period(AssetId::POLKADEX), (11, 1));
+		assert_ok!(PDEXMigration::cancel_mint(Origin::signed(GUARDIAN), deposit_id(2), 0));
+		assert_eq!(PDEXMigration::current_period(AssetId::POLKADEX), (11, 1));
+	});
+}
+
+#[test]
+fn pause_halts_bridge() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Tokens::deposit(AssetId::POLKADEX, &ALICE, UNIT));
+		assert_ok!(PDEXMigration::burn_for_erc20(
+			Origin::signed(ALICE),
+			UNIT,
+			H160::repeat_byte(1)
+		));
+		assert_ok!(PDEXMigration::set_mint_delay(Origin::root(), AssetId::POLKADEX, Some(0), 2));
+		assert_ok!(mint_with_quorum(1, BOB, erc20(UNIT)));
+
+		assert_noop!(PDEXMigration::pause(Origin::root()), DispatchError::BadOrigin);
+		assert_ok!(PDEXMigration::pause(Origin::signed(GUARDIAN)));
+		assert_eq!(last_event(), Event::PDEXMigration(crate::Event::BridgePaused));
+
+		assert_noop!(attest(10, 2, ALICE, erc20(UNIT)), Error::<Test>::BridgePaused);
+		assert_noop!(
+			PDEXMigration::submit_receipts_root(
+				Origin::signed(10),
+				block_hash(3),
+				H256::repeat_byte(9)
+			),
+			Error::<Test>::BridgePaused
+		);
+		assert_noop!(
+			PDEXMigration::burn_for_erc20(Origin::signed(BOB), UNIT, H160::repeat_byte(1)),
+			Error::<Test>::BridgePaused
+		);
+		assert_noop!(
+			PDEXMigration::confirm_withdrawal(Origin::signed(10), 0, H256::repeat_byte(7)),
+			Error::<Test>::BridgePaused
+		);
+
+		run_to_block(5);
+		assert_eq!(free_balance(BOB), 0);
+
+		assert_noop!(PDEXMigration::unpause(Origin::signed(ALICE)), DispatchError::BadOrigin);
+		assert_ok!(PDEXMigration::unpause(Origin::signed(GUARDIAN)));
+		run_to_block(6);
+		assert_eq!(free_balance(BOB), UNIT);
+	});
+}
+
+#[test]
+fn migration_moves_decl_storage_values() {
+	new_test_ext().execute_with(|| {
+		let address = H160::repeat_byte(0x11);
+		Address::<Test>::kill();
+		StorageVersion::new(0).put::<PDEXMigration>();
+		put_storage_value(migrations::v1::OLD_PREFIX, b"Address", &[], address);
+
+		migrations::v1::migrate::<Test>();
+		assert_eq!(PDEXMigration::address(), address);
+		assert_eq!(StorageVersion::get::<PDEXMigration>(), 1);
+
+		// Running the migration again is a no-op.
+		assert_eq!(migrations::v1::migrate::<Test>(), 0);
+	});
+}
+
+#[test]
+#[should_panic(expected = "Relayer threshold must not be zero")]
+fn genesis_rejects_zero_threshold() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		address: contract(),
+		relayers: vec![(10, relayer(10))],
+		threshold: 0,
+		migration_caps: vec![],
+		tokens: vec![],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+}
@@ -0,0 +1,240 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for erc20_pdex_migration_pallet
+//!
+//! These weights are estimates and have not been produced by the benchmark CLI yet. Replace this
+//! file with the output of `./scripts/benchmark.sh erc20_pdex_migration_pallet` run on the
+//! reference hardware.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for erc20_pdex_migration_pallet.
+pub trait WeightInfo {
+	fn mint(r: u32, p: u32) -> Weight;
+	fn submit_receipts_root(r: u32) -> Weight;
+	fn register_token() -> Weight;
+	fn deregister_token() -> Weight;
+	fn add_relayer() -> Weight;
+	fn remove_relayer() -> Weight;
+	fn set_threshold() -> Weight;
+	fn set_migration_cap() -> Weight;
+	fn set_mint_period_limit() -> Weight;
+	fn set_recipient_limit() -> Weight;
+	fn burn_for_erc20() -> Weight;
+	fn confirm_withdrawal(r: u32) -> Weight;
+	fn set_mint_delay() -> Weight;
+	fn cancel_mint() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn on_initialize(p: u32, q: u32) -> Weight;
+}
+
+/// Weights for erc20_pdex_migration_pallet using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn mint(r: u32, p: u32) -> Weight {
+		(112_406_000 as Weight)
+			.saturating_add((1_318_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add((4_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	fn submit_receipts_root(r: u32) -> Weight {
+		(27_306_000 as Weight)
+			.saturating_add((412_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	fn register_token() -> Weight {
+		(19_117_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn deregister_token() -> Weight {
+		(18_643_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn add_relayer() -> Weight {
+		(24_118_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn remove_relayer() -> Weight {
+		(25_305_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn set_threshold() -> Weight {
+		(18_250_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_migration_cap() -> Weight {
+		(15_012_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_mint_period_limit() -> Weight {
+		(15_734_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_recipient_limit() -> Weight {
+		(14_988_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn burn_for_erc20() -> Weight {
+		(61_527_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn confirm_withdrawal(r: u32) -> Weight {
+		(34_906_000 as Weight)
+			.saturating_add((402_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	fn set_mint_delay() -> Weight {
+		(16_301_000 as Weight).saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn cancel_mint() -> Weight {
+		(37_558_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn pause() -> Weight {
+		(13_874_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn unpause() -> Weight {
+		(13_902_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn on_initialize(p: u32, q: u32) -> Weight {
+		(4_720_000 as Weight)
+			.saturating_add((5_944_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((48_613_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(q as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn mint(r: u32, p: u32) -> Weight {
+		(112_406_000 as Weight)
+			.saturating_add((1_318_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add((4_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	fn submit_receipts_root(r: u32) -> Weight {
+		(27_306_000 as Weight)
+			.saturating_add((412_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	fn register_token() -> Weight {
+		(19_117_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn deregister_token() -> Weight {
+		(18_643_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn add_relayer() -> Weight {
+		(24_118_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn remove_relayer() -> Weight {
+		(25_305_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_threshold() -> Weight {
+		(18_250_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_migration_cap() -> Weight {
+		(15_012_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_mint_period_limit() -> Weight {
+		(15_734_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_recipient_limit() -> Weight {
+		(14_988_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn burn_for_erc20() -> Weight {
+		(61_527_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn confirm_withdrawal(r: u32) -> Weight {
+		(34_906_000 as Weight)
+			.saturating_add((402_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	fn set_mint_delay() -> Weight {
+		(16_301_000 as Weight).saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn cancel_mint() -> Weight {
+		(37_558_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn pause() -> Weight {
+		(13_874_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn unpause() -> Weight {
+		(13_902_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn on_initialize(p: u32, q: u32) -> Weight {
+		(4_720_000 as Weight)
+			.saturating_add((5_944_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((48_613_000 as Weight).saturating_mul(q as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(q as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(q as Weight)))
+	}
+}
@@ -0,0 +1,91 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for treasury-vesting-pallet
+
+use super::*;
+use crate::Pallet as TreasuryVesting;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Saturating, StaticLookup};
+
+const SEED: u32 = 0;
+
+/// Returns a schedule paying 100 times the existential deposit over two blocks.
+fn schedule<T: Config>() -> VestingScheduleOf<T> {
+	VestingSchedule {
+		start: 0u32.into(),
+		period: 1u32.into(),
+		period_count: 2,
+		per_period: T::Currency::minimum_balance().saturating_mul(50u32.into()),
+	}
+}
+
+/// Credits `who` with enough to reserve the bond of `schedule`.
+fn fund_proposer<T: Config>(who: &T::AccountId) {
+	let total = schedule::<T>()
+		.total_amount()
+		.expect("benchmark schedule does not overflow; qed");
+	let bond = TreasuryVesting::<T>::calculate_bond(total);
+	T::Currency::make_free_balance_be(who, bond.saturating_add(T::Currency::minimum_balance()));
+}
+
+/// Proposes a spend to a fresh beneficiary from a funded proposer.
+fn propose<T: Config>() -> Result<T::AccountId, &'static str> {
+	let proposer: T::AccountId = account("proposer", 0, SEED);
+	let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+	fund_proposer::<T>(&proposer);
+	TreasuryVesting::<T>::propose_spend(
+		RawOrigin::Signed(proposer).into(),
+		T::Lookup::unlookup(beneficiary.clone()),
+		schedule::<T>(),
+	)?;
+	Ok(beneficiary)
+}
+
+benchmarks! {
+	propose_spend {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		fund_proposer::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), T::Lookup::unlookup(beneficiary), schedule::<T>())
+	verify {
+		assert!(TreasuryVesting::<T>::proposals(0).is_some());
+	}
+
+	reject_proposal {
+		propose::<T>()?;
+		let origin = T::RejectOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0)
+	verify {
+		assert!(TreasuryVesting::<T>::proposals(0).is_none());
+	}
+
+	approve_proposal {
+		let beneficiary = propose::<T>()?;
+		T::VestedPayout::fund_treasury(T::Currency::minimum_balance().saturating_mul(1_000u32.into()));
+		let origin = T::ApproveOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0)
+	verify {
+		assert!(TreasuryVesting::<T>::proposals(0).is_none());
+		assert_eq!(T::Currency::free_balance(&beneficiary), schedule::<T>().total_amount().unwrap());
+	}
+}
+
+impl_benchmark_test_suite!(TreasuryVesting, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,249 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+//! # Treasury Vesting Pallet
+//!
+//! Pays treasury spends as vesting schedules instead of lump sums. Anyone may propose to pay a
+//! beneficiary along a `VestingSchedule`, reserving a bond like `pallet_treasury` proposals.
+//! `ApproveOrigin` pays an approved proposal out of the treasury through `VestedPayout` and
+//! returns the bond, while `RejectOrigin` rejects a proposal and slashes its bond.
+
+pub use orml_vesting::VestingSchedule;
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode};
+use frame_support::{dispatch::DispatchResult, traits::Currency};
+use sp_runtime::RuntimeDebug;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+/// Index of a vested spend proposal.
+pub type ProposalIndex = u32;
+
+/// Balance type of the treasury currency.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+/// Imbalance of slashed proposal bonds.
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+/// Vesting schedule of a spend.
+pub type VestingScheduleOf<T> =
+	VestingSchedule<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
+/// Vested spend proposal of the pallet.
+pub type VestedSpendOf<T> = VestedSpend<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// A proposal to pay the treasury's funds along a vesting schedule.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct VestedSpend<AccountId, Balance, BlockNumber> {
+	/// Account that proposed the spend
+	pub proposer: AccountId,
+	/// Account receiving the vested funds
+	pub beneficiary: AccountId,
+	/// Schedule the funds vest along
+	pub schedule: VestingSchedule<BlockNumber, Balance>,
+	/// Amount reserved from the proposer
+	pub bond: Balance,
+}
+
+/// Pays vested funds out of the treasury.
+pub trait VestedPayout<AccountId, BlockNumber, Balance> {
+	/// Transfers the total amount of `schedule` from the treasury to `to`, locked until it
+	/// vests.
+	fn vested_payout(
+		to: &AccountId,
+		schedule: VestingSchedule<BlockNumber, Balance>,
+	) -> DispatchResult;
+
+	/// Credits `amount` to the treasury so that benchmarks can pay spends.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn fund_treasury(amount: Balance);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{OnUnbalanced, ReservableCurrency},
+		transactional,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{StaticLookup, Zero},
+		Permill,
+	};
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Currency of the treasury and of proposal bonds
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Pays approved spends out of the treasury
+		type VestedPayout: VestedPayout<Self::AccountId, Self::BlockNumber, BalanceOf<Self>>;
+		/// Origin that approves spends
+		type ApproveOrigin: EnsureOrigin<Self::Origin>;
+		/// Origin that rejects spends
+		type RejectOrigin: EnsureOrigin<Self::Origin>;
+		/// Handler of slashed proposal bonds
+		type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// Fraction of the spent amount reserved as proposal bond
+		#[pallet::constant]
+		type ProposalBond: Get<Permill>;
+		/// Minimum proposal bond
+		#[pallet::constant]
+		type ProposalBondMinimum: Get<BalanceOf<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Number of proposals ever made
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_count)]
+	pub type ProposalCount<T> = StorageValue<_, ProposalIndex, ValueQuery>;
+
+	/// Vested spends awaiting approval or rejection
+	#[pallet::storage]
+	#[pallet::getter(fn proposals)]
+	pub type Proposals<T: Config> =
+		StorageMap<_, Twox64Concat, ProposalIndex, VestedSpendOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", BalanceOf<T> = "Balance")]
+	pub enum Event<T: Config> {
+		/// A vested spend was proposed. \[proposal_index\]
+		Proposed(ProposalIndex),
+		/// A vested spend was rejected and its bond slashed. \[proposal_index, slashed\]
+		Rejected(ProposalIndex, BalanceOf<T>),
+		/// A vested spend was paid. \[proposal_index, beneficiary, amount\]
+		Paid(ProposalIndex, T::AccountId, BalanceOf<T>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The proposer cannot reserve the proposal bond.
+		InsufficientProposersBalance,
+		/// No proposal has the given index.
+		InvalidIndex,
+		/// The vesting period is zero.
+		ZeroVestingPeriod,
+		/// The number of vesting periods is zero.
+		ZeroVestingPeriodCount,
+		/// The end or the amount of the schedule overflows.
+		NumOverflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Propose to pay `beneficiary` along `schedule`, reserving the proposal bond.
+		#[pallet::weight(T::WeightInfo::propose_spend())]
+		pub fn propose_spend(
+			origin: OriginFor<T>,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			let amount = Self::ensure_valid_vesting_schedule(&schedule)?;
+
+			let bond = Self::calculate_bond(amount);
+			T::Currency::reserve(&proposer, bond)
+				.map_err(|_| Error::<T>::InsufficientProposersBalance)?;
+
+			let index = Self::proposal_count();
+			ProposalCount::<T>::put(index + 1);
+			Proposals::<T>::insert(index, VestedSpend { proposer, beneficiary, schedule, bond });
+			Self::deposit_event(Event::Proposed(index));
+			Ok(())
+		}
+
+		/// Reject a proposed spend, slashing its bond.
+		#[pallet::weight(T::WeightInfo::reject_proposal())]
+		pub fn reject_proposal(
+			origin: OriginFor<T>,
+			#[pallet::compact] proposal_id: ProposalIndex,
+		) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			let proposal = Proposals::<T>::take(proposal_id).ok_or(Error::<T>::InvalidIndex)?;
+
+			let imbalance = T::Currency::slash_reserved(&proposal.proposer, proposal.bond).0;
+			T::OnSlash::on_unbalanced(imbalance);
+			Self::deposit_event(Event::Rejected(proposal_id, proposal.bond));
+			Ok(())
+		}
+
+		/// Approve a proposed spend, paying it out of the treasury along its schedule and
+		/// returning the bond.
+		#[pallet::weight(T::WeightInfo::approve_proposal())]
+		#[transactional]
+		pub fn approve_proposal(
+			origin: OriginFor<T>,
+			#[pallet::compact] proposal_id: ProposalIndex,
+		) -> DispatchResult {
+			T::ApproveOrigin::ensure_origin(origin)?;
+			let proposal = Proposals::<T>::take(proposal_id).ok_or(Error::<T>::InvalidIndex)?;
+			let amount = Self::ensure_valid_vesting_schedule(&proposal.schedule)?;
+
+			T::Currency::unreserve(&proposal.proposer, proposal.bond);
+			T::VestedPayout::vested_payout(&proposal.beneficiary, proposal.schedule)?;
+			Self::deposit_event(Event::Paid(proposal_id, proposal.beneficiary, amount));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Returns the bond of a spend of `amount`.
+		pub fn calculate_bond(amount: BalanceOf<T>) -> BalanceOf<T> {
+			T::ProposalBondMinimum::get().max(T::ProposalBond::get() * amount)
+		}
+
+		/// Returns the total amount of a valid `schedule`.
+		fn ensure_valid_vesting_schedule(
+			schedule: &VestingScheduleOf<T>,
+		) -> Result<BalanceOf<T>, Error<T>> {
+			ensure!(!schedule.period.is_zero(), Error::<T>::ZeroVestingPeriod);
+			ensure!(!schedule.period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+			ensure!(schedule.end().is_some(), Error::<T>::NumOverflow);
+			schedule.total_amount().ok_or(Error::<T>::NumOverflow)
+		}
+	}
+}
@@ -0,0 +1,142 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Error, VestedSpend, VestingSchedule};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+fn last_event() -> Event {
+	System::events().pop().expect("an event was deposited").event
+}
+
+/// Vests 400 over four periods of 10 blocks starting at block 1.
+fn schedule() -> VestingSchedule<u64, Balance> {
+	VestingSchedule { start: 1, period: 10, period_count: 4, per_period: 100 }
+}
+
+#[test]
+fn propose_spend_reserves_bond() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TreasuryVesting::propose_spend(Origin::signed(ALICE), BOB, schedule()));
+		// 5% of 400
+		assert_eq!(Balances::reserved_balance(ALICE), 20);
+		assert_eq!(TreasuryVesting::proposal_count(), 1);
+		assert_eq!(
+			TreasuryVesting::proposals(0),
+			Some(VestedSpend { proposer: ALICE, beneficiary: BOB, schedule: schedule(), bond: 20 })
+		);
+		assert_eq!(last_event(), Event::TreasuryVesting(crate::Event::Proposed(0)));
+
+		// small spends reserve the minimum bond
+		let small = VestingSchedule { start: 1, period: 1, period_count: 1, per_period: 10 };
+		assert_ok!(TreasuryVesting::propose_spend(Origin::signed(ALICE), BOB, small));
+		assert_eq!(Balances::reserved_balance(ALICE), 22);
+	});
+}
+
+#[test]
+fn propose_spend_rejects_invalid_proposals() {
+	new_test_ext().execute_with(|| {
+		let zero_period = VestingSchedule { period: 0, ..schedule() };
+		assert_noop!(
+			TreasuryVesting::propose_spend(Origin::signed(ALICE), BOB, zero_period),
+			Error::<Test>::ZeroVestingPeriod
+		);
+		let zero_count = VestingSchedule { period_count: 0, ..schedule() };
+		assert_noop!(
+			TreasuryVesting::propose_spend(Origin::signed(ALICE), BOB, zero_count),
+			Error::<Test>::ZeroVestingPeriodCount
+		);
+		let overflow = VestingSchedule { per_period: Balance::MAX, ..schedule() };
+		assert_noop!(
+			TreasuryVesting::propose_spend(Origin::signed(ALICE), BOB, overflow),
+			Error::<Test>::NumOverflow
+		);
+		assert_noop!(
+			TreasuryVesting::propose_spend(Origin::signed(BOB), ALICE, schedule()),
+			Error::<Test>::InsufficientProposersBalance
+		);
+	});
+}
+
+#[test]
+fn approve_proposal_pays_vesting_schedule() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TreasuryVesting::propose_spend(Origin::signed(ALICE), BOB, schedule()));
+		assert_noop!(
+			TreasuryVesting::approve_proposal(Origin::signed(ALICE), 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TreasuryVesting::approve_proposal(Origin::root(), 1),
+			Error::<Test>::InvalidIndex
+		);
+
+		assert_ok!(TreasuryVesting::approve_proposal(Origin::root(), 0));
+		assert_eq!(TreasuryVesting::proposals(0), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		assert_eq!(Balances::free_balance(TREASURY), 600);
+		assert_eq!(Balances::free_balance(BOB), 401);
+		assert_eq!(OrmlVesting::vesting_schedules(BOB).into_inner(), vec![schedule()]);
+		assert_eq!(last_event(), Event::TreasuryVesting(crate::Event::Paid(0, BOB, 400)));
+
+		// the spend vests along its schedule
+		assert!(Balances::transfer(Origin::signed(BOB), ALICE, 2).is_err());
+		System::set_block_number(21);
+		assert_ok!(OrmlVesting::claim(Origin::signed(BOB)));
+		assert_ok!(Balances::transfer(Origin::signed(BOB), ALICE, 200));
+		assert!(Balances::transfer(Origin::signed(BOB), ALICE, 2).is_err());
+	});
+}
+
+#[test]
+fn approve_proposal_keeps_unpayable_proposals() {
+	new_test_ext().execute_with(|| {
+		// 1200 exceeds the treasury's 1000
+		let large = VestingSchedule { per_period: 300, ..schedule() };
+		assert_ok!(TreasuryVesting::propose_spend(Origin::signed(ALICE), BOB, large));
+		assert!(TreasuryVesting::approve_proposal(Origin::root(), 0).is_err());
+		assert!(TreasuryVesting::proposals(0).is_some());
+		assert_eq!(Balances::reserved_balance(ALICE), 60);
+		assert_eq!(Balances::free_balance(TREASURY), 1_000);
+	});
+}
+
+#[test]
+fn reject_proposal_slashes_bond() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TreasuryVesting::propose_spend(Origin::signed(ALICE), BOB, schedule()));
+		assert_noop!(
+			TreasuryVesting::reject_proposal(Origin::signed(ALICE), 0),
+			DispatchError::BadOrigin
+		);
+
+		let issuance = Balances::total_issuance();
+		assert_ok!(TreasuryVesting::reject_proposal(Origin::root(), 0));
+		assert_eq!(TreasuryVesting::proposals(0), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 80);
+		assert_eq!(Balances::total_issuance(), issuance - 20);
+		assert_eq!(last_event(), Event::TreasuryVesting(crate::Event::Rejected(0, 20)));
+		assert_noop!(
+			TreasuryVesting::reject_proposal(Origin::root(), 0),
+			Error::<Test>::InvalidIndex
+		);
+	});
+}
@@ -0,0 +1,171 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for balances of native PDEX and `orml_tokens` assets.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use polkadex_primitives::assets::AssetId;
+use polkadex_runtime_api::AssetBalance;
+pub use polkadex_runtime_api::AssetsApi as AssetsRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Asset balances RPC methods.
+#[rpc]
+pub trait AssetsApi<BlockHash, AccountId> {
+	/// Returns the balance of `who` in `asset_id`.
+	#[rpc(name = "assets_getBalance")]
+	fn balance(
+		&self,
+		who: AccountId,
+		asset_id: AssetId,
+		at: Option<BlockHash>,
+	) -> Result<AssetBalance<NumberOrHex>>;
+
+	/// Returns the balances of `who` in native PDEX and every asset it holds.
+	#[rpc(name = "assets_getBalances")]
+	fn balances(
+		&self,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(AssetId, AssetBalance<NumberOrHex>)>>;
+
+	/// Returns the total issuance of `asset_id`.
+	#[rpc(name = "assets_getTotalIssuance")]
+	fn total_issuance(&self, asset_id: AssetId, at: Option<BlockHash>) -> Result<NumberOrHex>;
+
+	/// Returns the balances of the treasury in native PDEX and every asset it holds.
+	#[rpc(name = "assets_getTreasuryBalances")]
+	fn treasury_balances(
+		&self,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(AssetId, AssetBalance<NumberOrHex>)>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+/// Implements the `AssetsApi` RPC trait by calling into the runtime.
+pub struct Assets<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Assets<C, B> {
+	/// Create new `Assets` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, Balance> AssetsApi<<Block as BlockT>::Hash, AccountId>
+	for Assets<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: AssetsRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec + Into<NumberOrHex>,
+{
+	fn balance(
+		&self,
+		who: AccountId,
+		asset_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<AssetBalance<NumberOrHex>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.account_balance(&at, who, asset_id)
+			.map(|balance| balance.map(Into::into))
+			.map_err(|e| runtime_error("Unable to query asset balance.", e))
+	}
+
+	fn balances(
+		&self,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(AssetId, AssetBalance<NumberOrHex>)>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.account_balances(&at, who)
+			.map(|balances| {
+				balances
+					.into_iter()
+					.map(|(asset_id, balance)| (asset_id, balance.map(Into::into)))
+					.collect()
+			})
+			.map_err(|e| runtime_error("Unable to query asset balances.", e))
+	}
+
+	fn total_issuance(
+		&self,
+		asset_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<NumberOrHex> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.total_issuance(&at, asset_id)
+			.map(Into::into)
+			.map_err(|e| runtime_error("Unable to query total issuance.", e))
+	}
+
+	fn treasury_balances(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(AssetId, AssetBalance<NumberOrHex>)>> {
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		self.client
+			.runtime_api()
+			.treasury_balances(&at)
+			.map(|balances| {
+				balances
+					.into_iter()
+					.map(|(asset_id, balance)| (asset_id, balance.map(Into::into)))
+					.collect()
+			})
+			.map_err(|e| runtime_error("Unable to query treasury balances.", e))
+	}
+}
@@ -0,0 +1,66 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Runtime APIs of the Polkadex runtime.
+
+use codec::{Codec, Decode, Encode};
+use polkadex_primitives::assets::AssetId;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Balance of an account in one asset.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AssetBalance<Balance> {
+	/// Balance that can be transferred, including the frozen part
+	pub free: Balance,
+	/// Balance reserved by pallets
+	pub reserved: Balance,
+	/// Part of the free balance that cannot be transferred
+	pub frozen: Balance,
+}
+
+impl<Balance> AssetBalance<Balance> {
+	/// Converts every field of the balance.
+	pub fn map<B>(self, f: impl Fn(Balance) -> B) -> AssetBalance<B> {
+		AssetBalance { free: f(self.free), reserved: f(self.reserved), frozen: f(self.frozen) }
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// Balances of native PDEX and `orml_tokens` assets.
+	#[api_version(2)]
+	pub trait AssetsApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Balance of `who` in `asset_id`.
+		fn account_balance(who: AccountId, asset_id: AssetId) -> AssetBalance<Balance>;
+		/// Balances of `who` in native PDEX and every asset it holds.
+		fn account_balances(who: AccountId) -> Vec<(AssetId, AssetBalance<Balance>)>;
+		/// Total issuance of `asset_id`.
+		fn total_issuance(asset_id: AssetId) -> Balance;
+		/// Balances of the treasury account in native PDEX and every asset it holds.
+		fn treasury_balances() -> Vec<(AssetId, AssetBalance<Balance>)>;
+	}
+}
@@ -0,0 +1,60 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks of orml pallets measured against the Polkadex runtime.
+
+use crate::{AccountId, AssetRegistry, Balance, Origin, Runtime};
+use frame_support::assert_ok;
+use orml_traits::MultiCurrency;
+use polkadex_primitives::assets::AssetId;
+use sp_runtime::traits::StaticLookup;
+use sp_std::prelude::*;
+
+pub mod orml_currencies;
+pub mod orml_tokens;
+
+/// Asset transferred in the benchmarks.
+pub const ASSET: AssetId = AssetId::Asset(1);
+
+/// Returns the lookup source of `who`.
+pub fn lookup_of_account(who: AccountId) -> <<Runtime as frame_system::Config>::Lookup as StaticLookup>::Source {
+	<Runtime as frame_system::Config>::Lookup::unlookup(who)
+}
+
+/// Registers `ASSET` with an existential deposit of one unit, so that the benchmarked transfers
+/// pass the asset registry checks.
+pub fn register_asset() {
+	assert_ok!(AssetRegistry::force_register(
+		Origin::root(),
+		ASSET,
+		lookup_of_account(Default::default()),
+		b"Benchmark".to_vec(),
+		b"BNCH".to_vec(),
+		12,
+		1,
+	));
+}
+
+/// Sets the free balance of `who` in `currency_id` to `amount`.
+pub fn set_balance(currency_id: AssetId, who: &AccountId, amount: Balance) {
+	assert_ok!(<crate::Currencies as orml_traits::MultiCurrencyExtended<_>>::update_balance(
+		currency_id,
+		who,
+		amount as i128 - <crate::Currencies as MultiCurrency<_>>::free_balance(currency_id, who) as i128,
+	));
+}
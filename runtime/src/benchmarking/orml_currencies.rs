@@ -0,0 +1,86 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks of orml_currencies measured against the Polkadex runtime.
+
+use super::{lookup_of_account, register_asset, set_balance, ASSET};
+use crate::{constants::currency::PDEX, AccountId, Amount, Balance, Currencies, Runtime};
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+use polkadex_primitives::assets::AssetId;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+runtime_benchmarks! {
+	{ Runtime, orml_currencies }
+
+	// transfer of a non-native asset
+	transfer_non_native_currency {
+		register_asset();
+		let amount: Balance = 1_000 * PDEX;
+		let from: AccountId = whitelisted_caller();
+		set_balance(ASSET, &from, amount);
+		let to: AccountId = account("to", 0, SEED);
+	}: transfer(RawOrigin::Signed(from), lookup_of_account(to.clone()), ASSET, amount)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(ASSET, &to), amount);
+	}
+
+	// transfer of native PDEX
+	transfer_native_currency {
+		let amount: Balance = 1_000 * PDEX;
+		let from: AccountId = whitelisted_caller();
+		set_balance(AssetId::POLKADEX, &from, 2 * amount);
+		let to: AccountId = account("to", 0, SEED);
+	}: _(RawOrigin::Signed(from), lookup_of_account(to.clone()), amount)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(AssetId::POLKADEX, &to), amount);
+	}
+
+	// update the balance of a non-native asset
+	update_balance_non_native_currency {
+		register_asset();
+		let amount: Balance = 1_000 * PDEX;
+		let who: AccountId = account("who", 0, SEED);
+	}: update_balance(RawOrigin::Root, lookup_of_account(who.clone()), ASSET, amount as Amount)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(ASSET, &who), amount);
+	}
+
+	// create an account holding native PDEX
+	update_balance_native_currency_creating {
+		let amount: Balance = 1_000 * PDEX;
+		let who: AccountId = account("who", 0, SEED);
+	}: update_balance(RawOrigin::Root, lookup_of_account(who.clone()), AssetId::POLKADEX, amount as Amount)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(AssetId::POLKADEX, &who), amount);
+	}
+
+	// kill an account holding native PDEX
+	update_balance_native_currency_killing {
+		let amount: Balance = 1_000 * PDEX;
+		let who: AccountId = account("who", 0, SEED);
+		set_balance(AssetId::POLKADEX, &who, amount);
+	}: update_balance(RawOrigin::Root, lookup_of_account(who.clone()), AssetId::POLKADEX, -(amount as Amount))
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(AssetId::POLKADEX, &who), 0);
+	}
+}
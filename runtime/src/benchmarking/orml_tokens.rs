@@ -0,0 +1,56 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks of orml_tokens measured against the Polkadex runtime.
+
+use super::{lookup_of_account, register_asset, set_balance, ASSET};
+use crate::{constants::currency::PDEX, AccountId, Balance, Runtime, Tokens};
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+runtime_benchmarks! {
+	{ Runtime, orml_tokens }
+
+	transfer {
+		register_asset();
+		let amount: Balance = 1_000 * PDEX;
+		let from: AccountId = whitelisted_caller();
+		set_balance(ASSET, &from, amount);
+		let to: AccountId = account("to", 0, SEED);
+	}: _(RawOrigin::Signed(from), lookup_of_account(to.clone()), ASSET, amount)
+	verify {
+		assert_eq!(<Tokens as MultiCurrency<_>>::free_balance(ASSET, &to), amount);
+	}
+
+	transfer_all {
+		register_asset();
+		let amount: Balance = 1_000 * PDEX;
+		let from: AccountId = whitelisted_caller();
+		set_balance(ASSET, &from, amount);
+		let to: AccountId = account("to", 0, SEED);
+	}: _(RawOrigin::Signed(from.clone()), lookup_of_account(to.clone()), ASSET)
+	verify {
+		assert_eq!(<Tokens as MultiCurrency<_>>::free_balance(ASSET, &from), 0);
+		assert_eq!(<Tokens as MultiCurrency<_>>::free_balance(ASSET, &to), amount);
+	}
+}
@@ -37,7 +37,7 @@ use frame_support::{
     },
 };
 use frame_support::{PalletId, traits::InstanceFilter};
-use frame_support::traits::{OnUnbalanced, Contains, Everything};
+use frame_support::traits::{OnUnbalanced, Contains};
 use frame_system::{
     EnsureOneOf,
     EnsureRoot, limits::{BlockLength, BlockWeights}, RawOrigin,
@@ -45,7 +45,7 @@ use frame_system::{
 #[cfg(any(feature = "std", test))]
 pub use frame_system::Call as SystemCall;
 use orml_currencies::BasicCurrencyAdapter;
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
 #[cfg(any(feature = "std", test))]
 pub use pallet_balances::Call as BalancesCall;
 use pallet_grandpa::{AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList};
@@ -59,12 +59,13 @@ use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 pub use polkadex_primitives::{AccountId, Signature};
 pub use polkadex_primitives::{AccountIndex, Balance, BlockNumber, Hash, Index, Moment};
 use polkadex_primitives::assets::AssetId;
+use polkadex_runtime_api::AssetBalance;
 use sp_api::impl_runtime_apis;
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_core::{
     crypto::KeyTypeId,
     OpaqueMetadata,
-    u32_trait::{_1, _2, _3, _4, _5},
+    u32_trait::{_1, _2, _3, _4, _5, Value as U32},
 };
 use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{
@@ -75,7 +76,7 @@ use sp_runtime::{
 pub use sp_runtime::BuildStorage;
 use sp_runtime::curve::PiecewiseLinear;
 use sp_runtime::generic::Era;
-use sp_runtime::traits::{self, BlakeTwo256, Block as BlockT, ConvertInto, NumberFor, OpaqueKeys, SaturatedConversion, StaticLookup, Zero, BlockNumberProvider};
+use sp_runtime::traits::{self, BlakeTwo256, Block as BlockT, NumberFor, OpaqueKeys, SaturatedConversion, StaticLookup, Zero, BlockNumberProvider};
 use sp_runtime::traits::AccountIdConversion;
 use sp_runtime::transaction_validity::{
     TransactionPriority, TransactionSource, TransactionValidity,
@@ -95,6 +96,10 @@ pub mod impls;
 /// Constant values used within the runtime.
 pub mod constants;
 mod weights;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+/// Storage migrations run on runtime upgrades.
+pub mod migrations;
 
 // Make the WASM binary available.
 #[cfg(feature = "std")]
@@ -119,10 +124,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     // and set impl_version to 0. If only runtime
     // implementation changes and behavior does not, then leave spec_version as
     // is and increment impl_version.
-    spec_version: 268,
+    spec_version: 270,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 2,
+    transaction_version: 3,
 };
 
 /// The BABE epoch configuration at genesis.
@@ -148,18 +153,138 @@ pub struct DealWithFees;
 impl OnUnbalanced<NegativeImbalance> for DealWithFees {
     fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item=NegativeImbalance>) {
         if let Some(fees) = fees_then_tips.next() {
-            // for fees, 80% to treasury, 20% to author
-            let mut split = fees.ration(80, 20);
+            // shares are set by governance, 80% to treasury and 20% to author by default
+            let (mut to_treasury, mut to_author) = split_imbalance(fees, AssetFeePayment::fee_distribution());
             if let Some(tips) = fees_then_tips.next() {
-                // for tips, if any, 80% to treasury, 20% to author (though this can be anything)
-                tips.ration_merge_into(80, 20, &mut split);
+                let (treasury_tips, author_tips) = split_imbalance(tips, AssetFeePayment::tip_distribution());
+                to_treasury.subsume(treasury_tips);
+                to_author.subsume(author_tips);
             }
-            Treasury::on_unbalanced(split.0);
-            Author::on_unbalanced(split.1);
+            Treasury::on_unbalanced(to_treasury);
+            Author::on_unbalanced(to_author);
         }
     }
 }
 
+/// Splits `amount` into the treasury and author shares of `split`, burning the rest.
+fn split_imbalance(
+    amount: NegativeImbalance,
+    split: asset_fee_payment_pallet::FeeSplit,
+) -> (NegativeImbalance, NegativeImbalance) {
+    let (to_treasury, to_author, _) = split.split(amount.peek());
+    let (treasury, rest) = amount.split(to_treasury);
+    let (author, _burnt) = rest.split(to_author);
+    (treasury, author)
+}
+
+impl asset_fee_payment_pallet::OnAssetFees<Balance> for DealWithFees {
+    fn on_asset_fees(asset_id: AssetId, fee: Balance, tip: Balance) {
+        // same shares as for native fees and tips, burnt shares are not deposited
+        let (fee_to_treasury, fee_to_author, _) = AssetFeePayment::fee_distribution().split(fee);
+        let (tip_to_treasury, tip_to_author, _) = AssetFeePayment::tip_distribution().split(tip);
+        let to_author = fee_to_author.saturating_add(tip_to_author);
+        let mut to_treasury = fee_to_treasury.saturating_add(tip_to_treasury);
+        // The treasury takes the author's share if the author can not be credited.
+        if Currencies::deposit(asset_id, &Authorship::author(), to_author).is_err() {
+            to_treasury = to_treasury.saturating_add(to_author);
+        }
+        if let Err(e) = Currencies::deposit(asset_id, &TreasuryAccountId::get(), to_treasury) {
+            log::warn!(
+                target: "runtime::asset-fee-payment",
+                "burnt {} of asset {:?} fees: {:?}",
+                to_treasury,
+                asset_id,
+                e,
+            );
+        }
+    }
+}
+
+/// Balance of `who` in native PDEX, frozen for any reason.
+fn native_balance(who: &AccountId) -> AssetBalance<Balance> {
+    let account = System::account(who).data;
+    AssetBalance {
+        free: account.free,
+        reserved: account.reserved,
+        frozen: account.misc_frozen.max(account.fee_frozen),
+    }
+}
+
+/// Balance held in an `orml_tokens` account.
+fn asset_balance(account: orml_tokens::AccountData<Balance>) -> AssetBalance<Balance> {
+    AssetBalance { free: account.free, reserved: account.reserved, frozen: account.frozen }
+}
+
+/// Balances of `who` in native PDEX, listed first, and in every asset it holds.
+fn account_balances(who: &AccountId) -> Vec<(AssetId, AssetBalance<Balance>)> {
+    let mut balances = vec![(AssetId::POLKADEX, native_balance(who))];
+    balances.extend(
+        orml_tokens::Accounts::<Runtime>::iter_prefix(who)
+            .filter(|(asset_id, _)| *asset_id != AssetId::POLKADEX)
+            .map(|(asset_id, account)| (asset_id, asset_balance(account)))
+    );
+    balances
+}
+
+/// Native PDEX and the assets that are active in the asset registry.
+pub struct ActiveAssets;
+
+impl Contains<AssetId> for ActiveAssets {
+    fn contains(asset_id: &AssetId) -> bool {
+        *asset_id == AssetId::POLKADEX || AssetRegistry::is_active(*asset_id)
+    }
+}
+
+/// `Tokens` rejecting balance changes of assets that are not active in the asset registry.
+/// Every pallet moving assets uses it, directly or through `Currencies`.
+pub type FreezableTokens = asset_registry_pallet::FreezableAssets<Runtime, Tokens>;
+
+/// Registers the assets the asset pallet benchmarks run with, as the genesis has none.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct RegisterBenchmarkAsset;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl RegisterBenchmarkAsset {
+    fn create_asset(asset_id: AssetId) {
+        if AssetRegistry::assets(asset_id).is_none() {
+            AssetRegistry::force_register(
+                Origin::root(),
+                asset_id,
+                Indices::unlookup(TreasuryModuleAccount::get()),
+                b"Benchmark".to_vec(),
+                b"BENCH".to_vec(),
+                12,
+                0,
+            )
+            .expect("benchmark assets are registered once by root; qed");
+        }
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl asset_vesting_pallet::BenchmarkHelper for RegisterBenchmarkAsset {
+    fn create_asset(asset_id: AssetId) {
+        Self::create_asset(asset_id)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl asset_treasury_pallet::BenchmarkHelper for RegisterBenchmarkAsset {
+    fn create_asset(asset_id: AssetId) {
+        Self::create_asset(asset_id)
+    }
+}
+
+/// Rejects every `Tokens` dispatchable, as they move assets without going through
+/// `FreezableTokens`. Assets are transferred with `Currencies` instead.
+pub struct BaseFilter;
+
+impl Contains<Call> for BaseFilter {
+    fn contains(call: &Call) -> bool {
+        !matches!(call, Call::Tokens(..))
+    }
+}
+
 /// We assume that ~10% of the block weight is consumed by `on_initialize` handlers.
 /// This is used to limit the maximal weight of a single extrinsic.
 const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(10);
@@ -198,7 +323,7 @@ parameter_types! {
 const_assert!(NORMAL_DISPATCH_RATIO.deconstruct() >= AVERAGE_ON_INITIALIZE_RATIO.deconstruct());
 
 impl frame_system::Config for Runtime {
-    type BaseCallFilter = Everything;
+    type BaseCallFilter = BaseFilter;
     type BlockWeights = RuntimeBlockWeights;
     type BlockLength = RuntimeBlockLength;
     type DbWeight = RocksDbWeight;
@@ -265,6 +390,14 @@ pub enum ProxyType {
     NonTransfer,
     Governance,
     Staking,
+    /// Transfers of non-native orml assets only.
+    AssetTransfer,
+    /// Claims of `OrmlVesting` and `AssetVesting` schedules only.
+    Vesting,
+    /// Judgements of the identity registrar the proxied account represents.
+    IdentityJudgement,
+    /// Rejection of announcements made by other proxies of the account.
+    CancelProxy,
 }
 
 impl Default for ProxyType {
@@ -280,17 +413,45 @@ impl InstanceFilter<Call> for ProxyType {
             ProxyType::NonTransfer => !matches!(
                 c,
                 Call::Balances(..)
-                    | Call::Vesting(pallet_vesting::Call::vested_transfer(..))
+                    | Call::Currencies(..)
+                    | Call::Tokens(..)
+                    | Call::OrmlVesting(orml_vesting::Call::vested_transfer(..))
+                    | Call::AssetVesting(asset_vesting_pallet::Call::vested_transfer(..))
                     | Call::Indices(pallet_indices::Call::transfer(..))
+                    | Call::PDEXMigration(erc20_pdex_migration_pallet::Call::burn_for_erc20(..))
             ),
             ProxyType::Governance => matches!(
                 c,
-                Call::Council(..)
+                Call::Democracy(..)
+                    | Call::Council(..)
                     | Call::TechnicalCommittee(..)
                     | Call::Elections(..)
                     | Call::Treasury(..)
+                    | Call::TreasuryVesting(..)
+                    | Call::AssetTreasury(..)
             ),
             ProxyType::Staking => matches!(c, Call::Staking(..)),
+            ProxyType::AssetTransfer => match c {
+                Call::Currencies(orml_currencies::Call::transfer(_, currency_id, _)) => {
+                    *currency_id != AssetId::POLKADEX
+                }
+                Call::Utility(..) => true,
+                _ => false,
+            },
+            ProxyType::Vesting => matches!(
+                c,
+                Call::OrmlVesting(orml_vesting::Call::claim(..))
+                    | Call::AssetVesting(asset_vesting_pallet::Call::claim(..))
+                    | Call::Utility(..)
+            ),
+            ProxyType::IdentityJudgement => matches!(
+                c,
+                Call::Identity(pallet_identity::Call::provide_judgement(..)) | Call::Utility(..)
+            ),
+            ProxyType::CancelProxy => matches!(
+                c,
+                Call::Proxy(pallet_proxy::Call::reject_announcement(..)) | Call::Utility(..)
+            ),
         }
     }
     fn is_superset(&self, o: &Self) -> bool {
@@ -298,7 +459,14 @@ impl InstanceFilter<Call> for ProxyType {
             (x, y) if x == y => true,
             (ProxyType::Any, _) => true,
             (_, ProxyType::Any) => false,
-            (ProxyType::NonTransfer, _) => true,
+            (
+                ProxyType::NonTransfer,
+                ProxyType::Governance
+                    | ProxyType::Staking
+                    | ProxyType::Vesting
+                    | ProxyType::IdentityJudgement
+                    | ProxyType::CancelProxy,
+            ) => true,
             _ => false,
         }
     }
@@ -618,6 +786,52 @@ parameter_types! {
     pub const MaxProposals: u32 = 100;
 }
 
+impl pallet_democracy::Config for Runtime {
+    type Proposal = Call;
+    type Event = Event;
+    type Currency = Balances;
+    type EnactmentPeriod = EnactmentPeriod;
+    type LaunchPeriod = LaunchPeriod;
+    type VotingPeriod = VotingPeriod;
+    type VoteLockingPeriod = EnactmentPeriod; // Same as EnactmentPeriod
+    type MinimumDeposit = MinimumDeposit;
+    /// A straight majority of the council can decide what their next motion is.
+    type ExternalOrigin = pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, CouncilCollective>;
+    /// A super-majority can have the next scheduled referendum be a straight majority-carries vote.
+    type ExternalMajorityOrigin = pallet_collective::EnsureProportionAtLeast<_3, _4, AccountId, CouncilCollective>;
+    /// A unanimous council can have the next scheduled referendum be a straight default-carries
+    /// (NTB) vote.
+    type ExternalDefaultOrigin = pallet_collective::EnsureProportionAtLeast<_1, _1, AccountId, CouncilCollective>;
+    /// Two thirds of the technical committee can have an ExternalMajority/ExternalDefault vote
+    /// be tabled immediately and with a shorter voting/enactment period.
+    type FastTrackOrigin = pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCollective>;
+    type InstantOrigin = pallet_collective::EnsureProportionAtLeast<_1, _1, AccountId, TechnicalCollective>;
+    type InstantAllowed = InstantAllowed;
+    type FastTrackVotingPeriod = FastTrackVotingPeriod;
+    // To cancel a proposal which has been passed, 2/3 of the council must agree to it.
+    type CancellationOrigin = pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, CouncilCollective>;
+    // To cancel a proposal before it has been passed, the technical committee must be unanimous or
+    // Root must agree.
+    type CancelProposalOrigin = EnsureOneOf<
+        AccountId,
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<_1, _1, AccountId, TechnicalCollective>,
+    >;
+    type BlacklistOrigin = EnsureRootOrTwoThirdsCouncil;
+    // Any single technical committee member may veto a coming council proposal, however they can
+    // only do it once and it lasts only for the cool-off period.
+    type VetoOrigin = pallet_collective::EnsureMember<AccountId, TechnicalCollective>;
+    type CooloffPeriod = CooloffPeriod;
+    type PreimageByteDeposit = PreimageByteDeposit;
+    type OperationalPreimageOrigin = pallet_collective::EnsureMember<AccountId, CouncilCollective>;
+    type Slash = Treasury;
+    type Scheduler = Scheduler;
+    type PalletsOrigin = OriginCaller;
+    type MaxVotes = MaxVotes;
+    type WeightInfo = pallet_democracy::weights::SubstrateWeight<Runtime>;
+    type MaxProposals = MaxProposals;
+}
+
 
 parameter_types! {
     pub const CouncilMotionDuration: BlockNumber = 5 * DAY;
@@ -698,6 +912,12 @@ type EnsureRootOrHalfCouncil = EnsureOneOf<
     pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, CouncilCollective>,
 >;
 
+type EnsureRootOrTwoThirdsCouncil = EnsureOneOf<
+    AccountId,
+    EnsureRoot<AccountId>,
+    pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, CouncilCollective>,
+>;
+
 impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
     type Event = Event;
     type AddOrigin = EnsureRootOrHalfCouncil;
@@ -767,12 +987,6 @@ impl pallet_bounties::Config for Runtime {
     type WeightInfo = pallet_bounties::weights::SubstrateWeight<Runtime>;
 }
 
-
-impl pallet_sudo::Config for Runtime {
-    type Event = Event;
-    type Call = Call;
-}
-
 parameter_types! {
     pub const ImOnlineUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
      /// We prioritize im-online heartbeats over election solution submission.
@@ -812,7 +1026,7 @@ impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for R
             frame_system::CheckEra::<Runtime>::from(era),
             frame_system::CheckNonce::<Runtime>::from(nonce),
             frame_system::CheckWeight::<Runtime>::new(),
-            pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+            asset_fee_payment_pallet::ChargeAssetTxPayment::<Runtime>::from(tip, None),
         );
         let raw_payload = SignedPayload::new(call, extra)
             .map_err(|e| {
@@ -926,18 +1140,6 @@ impl pallet_recovery::Config for Runtime {
     type RecoveryDeposit = RecoveryDeposit;
 }
 
-// FIXME: Do we need this?
-impl pallet_vesting::Config for Runtime {
-    type Event = Event;
-    type Currency = Balances;
-    type BlockNumberToBalance = ConvertInto;
-    type MinVestedTransfer = MinVestedTransfer;
-    type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
-    // `VestingInfo` encode length is 36bytes. 28 schedules gets encoded as 1009 bytes, which is the
-    // highest number of schedules that encodes less than 2^10.
-    const MAX_VESTING_SCHEDULES: u32 = 28;
-}
-
 impl pallet_randomness_collective_flip::Config for Runtime {}
 
 
@@ -964,7 +1166,6 @@ construct_runtime!(
         TechnicalMembership: pallet_membership::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>} = 14,
         Grandpa: pallet_grandpa::{Pallet, Call, Storage, Config, Event, ValidateUnsigned} = 15,
         Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 16,
-        Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 17,
         ImOnline: pallet_im_online::{Pallet, Call, Storage, Event<T>, ValidateUnsigned, Config<T>} = 18,
         AuthorityDiscovery: pallet_authority_discovery::{Pallet, Config} = 19,
         Offences: pallet_offences::{Pallet, Storage, Event} = 20,
@@ -972,7 +1173,6 @@ construct_runtime!(
         RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage} = 22,
         Identity: pallet_identity::{Pallet, Call, Storage, Event<T>} = 23,
         Recovery: pallet_recovery::{Pallet, Call, Storage, Event<T>} = 24,
-        Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>, Config<T>} = 25,
         Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>} = 26,
         Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>} = 27,
         Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 28,
@@ -980,7 +1180,14 @@ construct_runtime!(
         // Pallets
         OrmlVesting: orml_vesting::{Pallet, Storage, Call, Event<T>, Config<T>} = 30,
         Currencies: orml_currencies::{Pallet, Call, Event<T>} = 31,
-        Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>} = 32
+        Tokens: orml_tokens::{Pallet, Call, Storage, Event<T>, Config<T>} = 32,
+        PDEXMigration: erc20_pdex_migration_pallet::{Pallet, Call, Storage, Config<T>, Event<T>} = 33,
+        AssetFeePayment: asset_fee_payment_pallet::{Pallet, Call, Storage, Event<T>} = 34,
+        AssetRegistry: asset_registry_pallet::{Pallet, Call, Storage, Config<T>, Event<T>} = 35,
+        AssetVesting: asset_vesting_pallet::{Pallet, Call, Storage, Config<T>, Event<T>} = 36,
+        TreasuryVesting: treasury_vesting_pallet::{Pallet, Call, Storage, Event<T>} = 37,
+        Democracy: pallet_democracy::{Pallet, Call, Storage, Config<T>, Event<T>} = 38,
+        AssetTreasury: asset_treasury_pallet::{Pallet, Call, Storage, Event<T>} = 39
     }
 );
 
@@ -1006,7 +1213,7 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    asset_fee_payment_pallet::ChargeAssetTxPayment<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -1021,7 +1228,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPallets,
-    (),
+    (migrations::MigratePalletVestingToOrml, migrations::RemoveSudo, migrations::UnifyTreasuryAccounts),
 >;
 
 impl_runtime_apis! {
@@ -1193,6 +1400,27 @@ impl_runtime_apis! {
         }
     }
 
+    impl polkadex_runtime_api::AssetsApi<Block, AccountId, Balance> for Runtime {
+        fn account_balance(who: AccountId, asset_id: AssetId) -> AssetBalance<Balance> {
+            match asset_id {
+                AssetId::POLKADEX => native_balance(&who),
+                AssetId::Asset(_) => asset_balance(Tokens::accounts(&who, asset_id)),
+            }
+        }
+
+        fn account_balances(who: AccountId) -> Vec<(AssetId, AssetBalance<Balance>)> {
+            account_balances(&who)
+        }
+
+        fn total_issuance(asset_id: AssetId) -> Balance {
+            Currencies::total_issuance(asset_id)
+        }
+
+        fn treasury_balances() -> Vec<(AssetId, AssetBalance<Balance>)> {
+            account_balances(&TreasuryModuleAccount::get())
+        }
+    }
+
     impl sp_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             SessionKeys::generate(seed)
@@ -1219,6 +1447,7 @@ impl_runtime_apis! {
             config: frame_benchmarking::BenchmarkConfig
         ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
             use frame_benchmarking::{Benchmarking, BenchmarkBatch, add_benchmark, TrackedStorageKey};
+            use orml_benchmarking::add_benchmark as orml_add_benchmark;
             // Trying to add benchmarks directly to the Session Pallet caused cyclic dependency
             // issues. To get around that, we separated the Session benchmarks into its own crate,
             // which is why we need these two lines below.
@@ -1253,6 +1482,7 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, pallet_balances, Balances);
             add_benchmark!(params, batches, pallet_bounties, Bounties);
             add_benchmark!(params, batches, pallet_collective, Council);
+            add_benchmark!(params, batches, pallet_democracy, Democracy);
             add_benchmark!(params, batches, pallet_election_provider_multi_phase, ElectionProviderMultiPhase);
             add_benchmark!(params, batches, pallet_elections_phragmen, Elections);
             add_benchmark!(params, batches, pallet_grandpa, Grandpa);
@@ -1262,6 +1492,14 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, pallet_membership, TechnicalMembership);
             add_benchmark!(params, batches, pallet_multisig, Multisig);
             add_benchmark!(params, batches, pallet_offences, OffencesBench::<Runtime>);
+            add_benchmark!(params, batches, erc20_pdex_migration_pallet, PDEXMigration);
+            add_benchmark!(params, batches, asset_fee_payment_pallet, AssetFeePayment);
+            add_benchmark!(params, batches, asset_registry_pallet, AssetRegistry);
+            add_benchmark!(params, batches, asset_vesting_pallet, AssetVesting);
+            add_benchmark!(params, batches, treasury_vesting_pallet, TreasuryVesting);
+            add_benchmark!(params, batches, asset_treasury_pallet, AssetTreasury);
+            orml_add_benchmark!(params, batches, orml_currencies, benchmarking::orml_currencies);
+            orml_add_benchmark!(params, batches, orml_tokens, benchmarking::orml_tokens);
             add_benchmark!(params, batches, pallet_proxy, Proxy);
             add_benchmark!(params, batches, pallet_scheduler, Scheduler);
             add_benchmark!(params, batches, pallet_session, SessionBench::<Runtime>);
@@ -1270,7 +1508,6 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, pallet_timestamp, Timestamp);
             add_benchmark!(params, batches, pallet_treasury, Treasury);
             add_benchmark!(params, batches, pallet_utility, Utility);
-            add_benchmark!(params, batches, pallet_vesting, Vesting);
             add_benchmark!(params, batches, pallet_verifier_lightclient, VerifierLightclient);
 
             if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
@@ -1279,58 +1516,67 @@ impl_runtime_apis! {
     }
 }
 
-const MODULE_ID: PalletId = PalletId(*b"cb/gover");
-
 pub type Amount = i128;
 
 /// Digest item type.
 pub type DigestItem = generic::DigestItem<Hash>;
 
 parameter_types! {
-    pub const PolkadexTreasuryModuleId: PalletId = PalletId(*b"polka/tr");
+    /// Account of `pallet_treasury`, holding every treasury fund.
+    pub TreasuryAccountId: AccountId = TreasuryPalletId::get().into_account();
 }
 parameter_types! {
-    pub TreasuryAccountId: AccountId = PolkadexTreasuryModuleId::get().into_account();
+    pub const GovernancePalletId: PalletId = PalletId(*b"cb/gover");
+    pub GovernanceAccount: AccountId = GovernancePalletId::get().into_account();
 }
-pub struct EnsureGovernance;
 
-impl EnsureOrigin<Origin> for EnsureGovernance {
+/// Accepts Root, at least `N / D` of the council and the unanimous technical committee. Every
+/// accepted origin resolves to `GovernanceAccount`.
+pub struct EnsureGovernance<N = _1, D = _2>(sp_std::marker::PhantomData<(N, D)>);
+
+impl<N: U32, D: U32> EnsureOrigin<Origin> for EnsureGovernance<N, D> {
     type Success = AccountId;
+
     fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
-        let bridge_id = MODULE_ID.into_account();
-        Into::<Result<RawOrigin<AccountId>, Origin>>::into(o).and_then(|o| match o {
-            RawOrigin::Signed(who) if who == bridge_id => Ok(bridge_id),
-            r => Err(Origin::from(r)),
-        })
+        EnsureOneOf::<
+            AccountId,
+            EnsureOneOf<
+                AccountId,
+                EnsureRoot<AccountId>,
+                pallet_collective::EnsureProportionAtLeast<N, D, AccountId, CouncilCollective>,
+            >,
+            pallet_collective::EnsureProportionAtLeast<_1, _1, AccountId, TechnicalCollective>,
+        >::try_origin(o).map(|_| GovernanceAccount::get())
     }
 
     #[cfg(feature = "runtime-benchmarks")]
     fn successful_origin() -> Origin {
-        Origin::from(RawOrigin::Signed(Default::default()))
+        Origin::root()
     }
 }
 
+/// Accepts Root, the treasury account and two thirds of the council. Every accepted
+/// origin resolves to the treasury account, which pays for the call.
 pub struct EnsureRootOrPolkadexTreasury;
 
 impl EnsureOrigin<Origin> for EnsureRootOrPolkadexTreasury {
     type Success = AccountId;
 
     fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+        let treasury = TreasuryAccountId::get();
+        let o = match EnsureRootOrTwoThirdsCouncil::try_origin(o) {
+            Ok(_) => return Ok(treasury),
+            Err(o) => o,
+        };
         Into::<Result<RawOrigin<AccountId>, Origin>>::into(o).and_then(|o| match o {
-            RawOrigin::Signed(caller) => {
-                if caller == PolkadexTreasuryModuleId::get().into_account() {
-                    Ok(caller)
-                } else {
-                    Err(Origin::from(Some(caller)))
-                }
-            }
+            RawOrigin::Signed(caller) if caller == treasury => Ok(caller),
             r => Err(Origin::from(r)),
         })
     }
 
     #[cfg(feature = "runtime-benchmarks")]
     fn successful_origin() -> Origin {
-        Origin::from(RawOrigin::Signed(Default::default()))
+        Origin::root()
     }
 }
 parameter_types! {
@@ -1362,18 +1608,23 @@ parameter_type_with_key! {
     pub ExistentialDeposits: |currency_id: AssetId| -> Balance {
         match currency_id {
             AssetId::POLKADEX => PDEX,
-            AssetId::Asset(_) => Zero::zero()
+            // unregistered assets keep a zero existential deposit
+            AssetId::Asset(_) => AssetRegistry::existential_deposit(*currency_id).unwrap_or_else(Zero::zero)
         }
     };
 }
 parameter_types! {
-    pub TreasuryModuleAccount: AccountId = PolkadexTreasuryModuleId::get().into_account();
+    pub TreasuryModuleAccount: AccountId = TreasuryAccountId::get();
 }
 
+/// Accounts whose `orml_tokens` balances are never removed as dust: the treasury, accounts
+/// derived from a `PalletId` and the accounts whitelisted by the council in `AssetRegistry`.
 pub struct DustRemovalWhitelist;
 impl Contains<AccountId> for DustRemovalWhitelist {
-    fn contains(_account: &AccountId) -> bool {
-        false
+    fn contains(account: &AccountId) -> bool {
+        *account == TreasuryModuleAccount::get()
+            || PalletId::try_from_account(account).is_some()
+            || AssetRegistry::contains(account)
     }
 }
 
@@ -1382,7 +1633,7 @@ impl orml_tokens::Config for Runtime {
     type Balance = Balance;
     type Amount = Amount;
     type CurrencyId = AssetId;
-    type WeightInfo = ();
+    type WeightInfo = weights::orml_tokens::WeightInfo<Runtime>;
     type ExistentialDeposits = ExistentialDeposits;
     type OnDust = orml_tokens::TransferDust<Runtime, TreasuryModuleAccount>;
     type MaxLocks = MaxLocks;
@@ -1395,15 +1646,138 @@ parameter_types! {
 
 impl orml_currencies::Config for Runtime {
     type Event = Event;
-    type MultiCurrency = Tokens;
+    type MultiCurrency = FreezableTokens;
     type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
     type GetNativeCurrencyId = GetNativeCurrencyId;
-    type WeightInfo = ();
+    type WeightInfo = weights::orml_currencies::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+    pub const MaxRelayers: u32 = 21;
+    pub const ProposalLifetime: BlockNumber = DAY;
+}
+
+impl erc20_pdex_migration_pallet::Config for Runtime {
+    type Event = Event;
+    type Balance = Balance;
+    type Currency = Currencies;
+    type CallOrigin = erc20_pdex_migration_pallet::EnsureRelayer<Runtime>;
+    type GovernanceOrigin = EnsureRootOrHalfCouncil;
+    type GuardianOrigin = EnsureOneOf<
+        AccountId,
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, TechnicalCollective>,
+    >;
+    type EthereumHeaders = PDEXMigration;
+    type ActiveAssets = ActiveAssets;
+    type MaxRelayers = MaxRelayers;
+    type ProposalLifetime = ProposalLifetime;
+    type WeightInfo = erc20_pdex_migration_pallet::weights::SubstrateWeight<Runtime>;
+}
+
+impl asset_fee_payment_pallet::Config for Runtime {
+    type Event = Event;
+    type Currency = Currencies;
+    type RateOrigin = EnsureRootOrHalfCouncil;
+    type OnAssetFees = DealWithFees;
+    type FeeSplitOrigin = EnsureRootOrHalfCouncil;
+    type WeightInfo = asset_fee_payment_pallet::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const AssetDeposit: Balance = 100 * PDEX;
+    pub const AssetStringLimit: u32 = 50;
+    pub const MaxAssetExistentialDeposit: Balance = 1_000 * PDEX;
+}
+
+impl asset_registry_pallet::Config for Runtime {
+    type Event = Event;
+    type Currency = Balances;
+    type Assets = Tokens;
+    type GovernanceOrigin = EnsureRootOrHalfCouncil;
+    type AssetDeposit = AssetDeposit;
+    type StringLimit = AssetStringLimit;
+    type MaxExistentialDeposit = MaxAssetExistentialDeposit;
+    type WeightInfo = asset_registry_pallet::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    /// Keeps vested transfers from creating schedules too small to be worth their storage.
+    pub const MinVestedAssetTransfer: Balance = PDEX;
+}
+
+impl asset_vesting_pallet::Config for Runtime {
+    type Event = Event;
+    type Currency = FreezableTokens;
+    type MinVestedTransfer = MinVestedAssetTransfer;
+    type VestedTransferOrigin = EnsureRootOrPolkadexTreasury;
+    type MaxVestingSchedules = MaxVestingSchedules;
+    type BlockNumberProvider = SusbtrateBlockNumberProvider;
+    type WeightInfo = asset_vesting_pallet::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RegisterBenchmarkAsset;
+}
+
+/// Pays vested treasury spends from the Polkadex treasury account with `orml_vesting`.
+pub struct PayVestedFromTreasury;
+
+impl treasury_vesting_pallet::VestedPayout<AccountId, BlockNumber, Balance> for PayVestedFromTreasury {
+    fn vested_payout(
+        to: &AccountId,
+        schedule: orml_vesting::VestingSchedule<BlockNumber, Balance>,
+    ) -> sp_runtime::DispatchResult {
+        OrmlVesting::vested_transfer(Origin::root(), sp_runtime::MultiAddress::Id(to.clone()), schedule)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn fund_treasury(amount: Balance) {
+        Balances::make_free_balance_be(&TreasuryAccountId::get(), amount);
+    }
+}
+
+impl treasury_vesting_pallet::Config for Runtime {
+    type Event = Event;
+    type Currency = Balances;
+    type VestedPayout = PayVestedFromTreasury;
+    type ApproveOrigin = EnsureOneOf<
+        AccountId,
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<_3, _5, AccountId, CouncilCollective>,
+    >;
+    type RejectOrigin = EnsureRootOrHalfCouncil;
+    type OnSlash = Treasury;
+    type ProposalBond = ProposalBond;
+    type ProposalBondMinimum = ProposalBondMinimum;
+    type WeightInfo = treasury_vesting_pallet::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const AssetSpendBond: Balance = 10 * DOLLAR;
+}
+
+impl asset_treasury_pallet::Config for Runtime {
+    type Event = Event;
+    type Currency = Balances;
+    type Assets = Currencies;
+    type TreasuryAccount = TreasuryModuleAccount;
+    type ApproveOrigin = EnsureOneOf<
+        AccountId,
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<_3, _5, AccountId, CouncilCollective>,
+    >;
+    type RejectOrigin = EnsureRootOrHalfCouncil;
+    type BurnOrigin = EnsureRootOrTwoThirdsCouncil;
+    type OnSlash = Treasury;
+    type ProposalBond = AssetSpendBond;
+    type WeightInfo = asset_treasury_pallet::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = RegisterBenchmarkAsset;
 }
 
 #[cfg(test)]
 mod tests {
     use frame_system::offchain::CreateSignedTransaction;
+    use sp_core::H160;
 
     use super::*;
 
@@ -1416,4 +1790,666 @@ mod tests {
 
         is_submit_signed_transaction::<Runtime>();
     }
+
+    #[test]
+    fn pdex_migration_mints_through_runtime() {
+        use erc20_pdex_migration_pallet::ethereum::fixtures;
+        use frame_support::{assert_noop, assert_ok, traits::GenesisBuild};
+        use sp_core::{H256, U256};
+
+        let relayers: Vec<AccountId> = vec![[1u8; 32].into(), [2u8; 32].into()];
+        let recipient: AccountId = [3u8; 32].into();
+        let contract = H160::repeat_byte(0xaa);
+        let token = H160::repeat_byte(0xdd);
+        let block_hash = H256::repeat_byte(1);
+        let amount = U256::from(10 * PDEX) * U256::exp10(6);
+        let receipt = fixtures::locked_receipt(
+            contract,
+            token,
+            H160::repeat_byte(0xbb),
+            &recipient.encode(),
+            amount,
+        );
+        let (receipts_root, proof) = fixtures::single_receipt_proof(0, &receipt);
+
+        let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        erc20_pdex_migration_pallet::GenesisConfig::<Runtime> {
+            address: contract,
+            relayers: relayers
+                .iter()
+                .cloned()
+                .zip(vec![H160::repeat_byte(1), H160::repeat_byte(2)])
+                .collect(),
+            threshold: 2,
+            migration_caps: vec![],
+            tokens: vec![(token, AssetId::POLKADEX, 18)],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            System::set_block_number(1);
+            for relayer in &relayers {
+                assert_ok!(PDEXMigration::submit_receipts_root(
+                    Origin::signed(relayer.clone()),
+                    block_hash,
+                    receipts_root
+                ));
+            }
+            let mint = |who: AccountId| {
+                PDEXMigration::mint(Origin::signed(who), block_hash, 0, proof.clone(), 0)
+            };
+
+            assert_noop!(
+                mint(recipient.clone()),
+                erc20_pdex_migration_pallet::Error::<Runtime>::NotRelayer
+            );
+            assert_ok!(mint(relayers[0].clone()));
+            assert_eq!(Balances::free_balance(&recipient), 0);
+            assert_ok!(mint(relayers[1].clone()));
+            assert_eq!(Balances::free_balance(&recipient), 10 * PDEX);
+            assert!(PDEXMigration::is_deposit_processed(PDEXMigration::deposit_id(block_hash, 0), 0));
+        });
+    }
+
+    #[test]
+    fn proxy_types_filter_calls() {
+        use ProxyType::*;
+
+        let dest = || sp_runtime::MultiAddress::Id(AccountId::from([1u8; 32]));
+        let calls = vec![
+            ("remark", Call::System(frame_system::Call::remark(vec![]))),
+            ("batch", Call::Utility(pallet_utility::Call::batch(vec![]))),
+            ("balances_transfer", Call::Balances(pallet_balances::Call::transfer(dest(), PDEX))),
+            ("native_transfer", Call::Currencies(orml_currencies::Call::transfer_native_currency(dest(), PDEX))),
+            ("pdex_transfer", Call::Currencies(orml_currencies::Call::transfer(dest(), AssetId::POLKADEX, PDEX))),
+            ("asset_transfer", Call::Currencies(orml_currencies::Call::transfer(dest(), AssetId::Asset(1), PDEX))),
+            ("tokens_transfer", Call::Tokens(orml_tokens::Call::transfer(dest(), AssetId::Asset(1), PDEX))),
+            ("vested_transfer", Call::OrmlVesting(orml_vesting::Call::vested_transfer(
+                dest(),
+                orml_vesting::VestingSchedule { start: 0, period: 1, period_count: 1, per_period: PDEX },
+            ))),
+            ("vesting_claim", Call::OrmlVesting(orml_vesting::Call::claim())),
+            ("asset_vested_transfer", Call::AssetVesting(asset_vesting_pallet::Call::vested_transfer(
+                dest(),
+                AssetId::Asset(1),
+                asset_vesting_pallet::VestingSchedule { start: 0, period: 1, period_count: 1, per_period: PDEX },
+            ))),
+            ("asset_vesting_claim", Call::AssetVesting(asset_vesting_pallet::Call::claim(AssetId::Asset(1)))),
+            ("indices_transfer", Call::Indices(pallet_indices::Call::transfer([1u8; 32].into(), 0))),
+            ("burn_for_erc20", Call::PDEXMigration(erc20_pdex_migration_pallet::Call::burn_for_erc20(PDEX, H160::zero()))),
+            ("democracy", Call::Democracy(pallet_democracy::Call::second(0, 0))),
+            ("treasury", Call::Treasury(pallet_treasury::Call::reject_proposal(0))),
+            ("treasury_vesting", Call::TreasuryVesting(treasury_vesting_pallet::Call::reject_proposal(0))),
+            ("asset_treasury", Call::AssetTreasury(asset_treasury_pallet::Call::reject_proposal(0))),
+            ("staking", Call::Staking(pallet_staking::Call::chill())),
+            ("judgement", Call::Identity(pallet_identity::Call::provide_judgement(
+                0,
+                dest(),
+                pallet_identity::Judgement::Reasonable,
+            ))),
+            ("reject_announcement", Call::Proxy(pallet_proxy::Call::reject_announcement(
+                [1u8; 32].into(),
+                Hash::zero(),
+            ))),
+        ];
+        let allowed: Vec<(ProxyType, Vec<&str>)> = vec![
+            (Any, calls.iter().map(|(name, _)| *name).collect()),
+            (NonTransfer, vec![
+                "remark", "batch", "vesting_claim", "asset_vesting_claim", "democracy", "treasury", "treasury_vesting",
+                "asset_treasury", "staking", "judgement", "reject_announcement",
+            ]),
+            (Governance, vec!["democracy", "treasury", "treasury_vesting", "asset_treasury"]),
+            (Staking, vec!["staking"]),
+            (AssetTransfer, vec!["batch", "asset_transfer"]),
+            (Vesting, vec!["batch", "vesting_claim", "asset_vesting_claim"]),
+            (IdentityJudgement, vec!["batch", "judgement"]),
+            (CancelProxy, vec!["batch", "reject_announcement"]),
+        ];
+
+        for (proxy_type, allowed) in &allowed {
+            for (name, call) in &calls {
+                assert_eq!(
+                    proxy_type.filter(call),
+                    allowed.contains(name),
+                    "{:?} filtering {}",
+                    proxy_type,
+                    name,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn proxy_types_are_ordered_by_filtered_calls() {
+        use ProxyType::*;
+
+        let all = [Any, NonTransfer, Governance, Staking, AssetTransfer, Vesting, IdentityJudgement, CancelProxy];
+        let supersets = |proxy_type: ProxyType| -> Vec<ProxyType> {
+            match proxy_type {
+                Any => all.to_vec(),
+                NonTransfer => vec![NonTransfer, Governance, Staking, Vesting, IdentityJudgement, CancelProxy],
+                other => vec![other],
+            }
+        };
+
+        for x in &all {
+            for y in &all {
+                assert_eq!(x.is_superset(y), supersets(*x).contains(y), "{:?} superset of {:?}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn fees_and_tips_are_distributed_by_governance_split() {
+        use asset_fee_payment_pallet::FeeSplit;
+        use frame_support::assert_ok;
+
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            let author = Authorship::author();
+            let treasury = Treasury::account_id();
+
+            // 80% to treasury and 20% to author by default
+            DealWithFees::on_unbalanceds(
+                vec![Balances::issue(100 * PDEX), Balances::issue(10 * PDEX)].into_iter(),
+            );
+            assert_eq!(Balances::free_balance(&treasury), 88 * PDEX);
+            assert_eq!(Balances::free_balance(&author), 22 * PDEX);
+            assert_eq!(Balances::total_issuance(), 110 * PDEX);
+
+            let fees = FeeSplit {
+                treasury: Percent::from_percent(50),
+                author: Percent::from_percent(10),
+                burn: Percent::from_percent(40),
+            };
+            let tips = FeeSplit {
+                treasury: Percent::from_percent(0),
+                author: Percent::from_percent(100),
+                burn: Percent::from_percent(0),
+            };
+            assert_ok!(AssetFeePayment::set_fee_distribution(Origin::root(), fees, tips));
+            DealWithFees::on_unbalanceds(
+                vec![Balances::issue(100 * PDEX), Balances::issue(10 * PDEX)].into_iter(),
+            );
+            assert_eq!(Balances::free_balance(&treasury), (88 + 50) * PDEX);
+            assert_eq!(Balances::free_balance(&author), (22 + 10 + 10) * PDEX);
+            assert_eq!(Balances::total_issuance(), (110 + 70) * PDEX);
+        });
+    }
+
+    #[test]
+    fn asset_transfers_require_active_registered_asset() {
+        use frame_support::{assert_noop, assert_ok};
+        use orml_traits::GetByKey;
+
+        let asset = AssetId::Asset(1);
+        let alice = AccountId::from([1u8; 32]);
+        let bob = AccountId::from([2u8; 32]);
+        let treasury = TreasuryAccountId::get();
+        let schedule = asset_vesting_pallet::VestingSchedule { start: 0, period: 10, period_count: 4, per_period: PDEX };
+        let not_active: sp_runtime::DispatchError = asset_registry_pallet::Error::<Runtime>::AssetNotActive.into();
+
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            assert_noop!(Currencies::deposit(asset, &alice, 10 * PDEX), not_active);
+            assert_eq!(<Runtime as orml_tokens::Config>::ExistentialDeposits::get(&asset), 0);
+
+            assert_ok!(AssetRegistry::force_register(
+                Origin::root(),
+                asset,
+                sp_runtime::MultiAddress::Id(AccountId::from([2u8; 32])),
+                b"Tether USD".to_vec(),
+                b"USDT".to_vec(),
+                6,
+                PDEX / 100,
+            ));
+            assert_eq!(<Runtime as orml_tokens::Config>::ExistentialDeposits::get(&asset), PDEX / 100);
+            assert_ok!(Currencies::deposit(asset, &alice, 10 * PDEX));
+            assert_ok!(Currencies::deposit(asset, &treasury, 10 * PDEX));
+            assert_ok!(<Currencies as MultiCurrency<_>>::transfer(asset, &alice, &bob, PDEX));
+
+            // frozen assets do not move through any pallet using `Currencies` or `FreezableTokens`
+            assert_ok!(AssetRegistry::freeze(Origin::root(), asset));
+            assert_noop!(<Currencies as MultiCurrency<_>>::transfer(asset, &alice, &bob, PDEX), not_active);
+            assert_noop!(Currencies::withdraw(asset, &alice, PDEX), not_active);
+            assert_noop!(
+                AssetVesting::vested_transfer(Origin::root(), sp_runtime::MultiAddress::Id(bob.clone()), asset, schedule),
+                not_active
+            );
+
+            assert_ok!(AssetRegistry::thaw(Origin::root(), asset));
+            assert_ok!(<Currencies as MultiCurrency<_>>::transfer(asset, &alice, &bob, PDEX));
+        });
+    }
+
+    #[test]
+    fn tokens_calls_are_filtered_and_use_benchmarked_weights() {
+        use frame_support::{assert_ok, dispatch::GetDispatchInfo, traits::UnfilteredDispatchable};
+        use orml_tokens::WeightInfo;
+        use sp_runtime::traits::Dispatchable;
+
+        let asset = AssetId::Asset(1);
+        let alice = AccountId::from([1u8; 32]);
+        let bob = AccountId::from([2u8; 32]);
+        let transfer = |asset_id| Call::Tokens(orml_tokens::Call::transfer(
+            sp_runtime::MultiAddress::Id(bob.clone()),
+            asset_id,
+            10 * PDEX,
+        ));
+        assert_eq!(
+            transfer(asset).get_dispatch_info().weight,
+            weights::orml_tokens::WeightInfo::<Runtime>::transfer()
+        );
+
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            assert_ok!(AssetRegistry::force_register(
+                Origin::root(),
+                asset,
+                sp_runtime::MultiAddress::Id(alice.clone()),
+                vec![],
+                vec![],
+                12,
+                0,
+            ));
+            assert_ok!(Tokens::deposit(asset, &alice, 100 * PDEX));
+            assert_ok!(Tokens::deposit(AssetId::POLKADEX, &alice, 100 * PDEX));
+
+            // active assets are moved with `Currencies`, which checks the asset status
+            assert!(transfer(asset).dispatch(Origin::signed(alice.clone())).is_err());
+            assert!(transfer(AssetId::POLKADEX).dispatch(Origin::signed(alice.clone())).is_err());
+            assert!(!BaseFilter::contains(&Call::Tokens(orml_tokens::Call::transfer_all(
+                sp_runtime::MultiAddress::Id(bob.clone()),
+                asset,
+            ))));
+            assert_eq!(Tokens::free_balance(asset, &bob), 0);
+            assert_ok!(transfer(asset).dispatch_bypass_filter(Origin::signed(alice.clone())));
+            assert_eq!(Tokens::free_balance(asset, &bob), 10 * PDEX);
+        });
+    }
+
+    #[test]
+    fn dust_is_swept_to_treasury_unless_whitelisted() {
+        use frame_support::assert_ok;
+
+        let asset = AssetId::Asset(1);
+        let alice = AccountId::from([1u8; 32]);
+        let council_member = AccountId::from([2u8; 32]);
+        let pallet_account: AccountId = GovernancePalletId::get().into_account();
+        let treasury = TreasuryModuleAccount::get();
+
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            assert_ok!(AssetRegistry::force_register(
+                Origin::root(),
+                asset,
+                sp_runtime::MultiAddress::Id(alice.clone()),
+                vec![],
+                vec![],
+                12,
+                10,
+            ));
+            assert!(DustRemovalWhitelist::contains(&treasury));
+            assert!(DustRemovalWhitelist::contains(&pallet_account));
+            assert!(!DustRemovalWhitelist::contains(&alice));
+
+            // normal accounts below the existential deposit lose their dust to the treasury
+            assert_ok!(Tokens::deposit(asset, &alice, 100));
+            assert_ok!(Tokens::withdraw(asset, &alice, 95));
+            assert_eq!(Tokens::free_balance(asset, &alice), 0);
+            assert_eq!(Tokens::free_balance(asset, &treasury), 5);
+
+            assert_ok!(Tokens::deposit(asset, &pallet_account, 100));
+            assert_ok!(Tokens::withdraw(asset, &pallet_account, 95));
+            assert_eq!(Tokens::free_balance(asset, &pallet_account), 5);
+
+            assert_ok!(AssetRegistry::add_to_dust_whitelist(
+                Origin::root(),
+                sp_runtime::MultiAddress::Id(council_member.clone()),
+            ));
+            assert_ok!(Tokens::deposit(asset, &council_member, 100));
+            assert_ok!(Tokens::withdraw(asset, &council_member, 95));
+            assert_eq!(Tokens::free_balance(asset, &council_member), 5);
+            assert_eq!(Tokens::free_balance(asset, &treasury), 5);
+
+            assert_ok!(AssetRegistry::remove_from_dust_whitelist(
+                Origin::root(),
+                sp_runtime::MultiAddress::Id(council_member.clone()),
+            ));
+            assert_ok!(Tokens::withdraw(asset, &council_member, 1));
+            assert_eq!(Tokens::free_balance(asset, &council_member), 0);
+            assert_eq!(Tokens::free_balance(asset, &treasury), 9);
+        });
+    }
+
+    #[test]
+    fn assets_api_reports_native_and_asset_balances() {
+        use frame_support::{assert_ok, traits::ReservableCurrency};
+        use polkadex_runtime_api::runtime_decl_for_AssetsApi::AssetsApi;
+
+        let who = AccountId::from([1u8; 32]);
+        let balance = |free, reserved, frozen| AssetBalance { free, reserved, frozen };
+
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            let _ = Balances::deposit_creating(&who, 10 * PDEX);
+            assert_ok!(Balances::reserve(&who, 2 * PDEX));
+            assert_ok!(Tokens::deposit(AssetId::Asset(1), &who, 5 * PDEX));
+            assert_ok!(Tokens::deposit(AssetId::Asset(2), &who, 7 * PDEX));
+            assert_ok!(<Tokens as orml_traits::MultiLockableCurrency<_>>::set_lock(
+                *b"testlock",
+                AssetId::Asset(2),
+                &who,
+                3 * PDEX,
+            ));
+
+            assert_eq!(Runtime::account_balance(who.clone(), AssetId::POLKADEX), balance(8 * PDEX, 2 * PDEX, 0));
+            assert_eq!(Runtime::account_balance(who.clone(), AssetId::Asset(2)), balance(7 * PDEX, 0, 3 * PDEX));
+            assert_eq!(Runtime::account_balance(who.clone(), AssetId::Asset(3)), balance(0, 0, 0));
+
+            let balances = Runtime::account_balances(who.clone());
+            assert_eq!(balances.len(), 3);
+            assert_eq!(balances[0], (AssetId::POLKADEX, balance(8 * PDEX, 2 * PDEX, 0)));
+            assert!(balances.contains(&(AssetId::Asset(1), balance(5 * PDEX, 0, 0))));
+            assert!(balances.contains(&(AssetId::Asset(2), balance(7 * PDEX, 0, 3 * PDEX))));
+
+            assert_eq!(Runtime::total_issuance(AssetId::POLKADEX), 10 * PDEX);
+            assert_eq!(Runtime::total_issuance(AssetId::Asset(2)), 7 * PDEX);
+        });
+    }
+
+    #[test]
+    fn treasury_vests_assets_to_investors() {
+        use frame_support::{assert_noop, assert_ok};
+
+        let asset = AssetId::Asset(1);
+        let investor = AccountId::from([1u8; 32]);
+        let other = AccountId::from([2u8; 32]);
+        let treasury = TreasuryAccountId::get();
+        let schedule = asset_vesting_pallet::VestingSchedule { start: 0, period: 10, period_count: 4, per_period: PDEX };
+
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(AssetRegistry::force_register(
+                Origin::root(),
+                asset,
+                sp_runtime::MultiAddress::Id(treasury.clone()),
+                vec![],
+                vec![],
+                12,
+                0,
+            ));
+            assert_ok!(Tokens::deposit(asset, &treasury, 10 * PDEX));
+
+            assert!(AssetVesting::vested_transfer(
+                Origin::signed(investor.clone()),
+                sp_runtime::MultiAddress::Id(investor.clone()),
+                asset,
+                schedule.clone(),
+            ).is_err());
+            assert_noop!(
+                AssetVesting::vested_transfer(
+                    Origin::signed(treasury.clone()),
+                    sp_runtime::MultiAddress::Id(investor.clone()),
+                    asset,
+                    asset_vesting_pallet::VestingSchedule { start: 0, period: 10, period_count: 4, per_period: 1 },
+                ),
+                asset_vesting_pallet::Error::<Runtime>::AmountLow,
+            );
+            assert_ok!(AssetVesting::vested_transfer(
+                Origin::signed(treasury.clone()),
+                sp_runtime::MultiAddress::Id(investor.clone()),
+                asset,
+                schedule,
+            ));
+            assert_eq!(Tokens::free_balance(asset, &investor), 4 * PDEX);
+            assert_noop!(
+                <Tokens as MultiCurrency<_>>::transfer(asset, &investor, &other, PDEX),
+                orml_tokens::Error::<Runtime>::LiquidityRestrictions,
+            );
+
+            System::set_block_number(21);
+            assert_ok!(AssetVesting::claim(Origin::signed(investor.clone()), asset));
+            assert_ok!(<Tokens as MultiCurrency<_>>::transfer(asset, &investor, &other, 2 * PDEX));
+            assert_noop!(
+                <Tokens as MultiCurrency<_>>::transfer(asset, &investor, &other, PDEX),
+                orml_tokens::Error::<Runtime>::LiquidityRestrictions,
+            );
+        });
+    }
+
+    #[test]
+    fn governance_spends_and_burns_treasury_dust() {
+        use frame_support::assert_ok;
+        use polkadex_runtime_api::runtime_decl_for_AssetsApi::AssetsApi;
+
+        let asset = AssetId::Asset(1);
+        let proposer = AccountId::from([1u8; 32]);
+        let beneficiary = AccountId::from([2u8; 32]);
+        let treasury = TreasuryModuleAccount::get();
+        let council = |yes, all| Origin::from(pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(yes, all));
+
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            assert_ok!(AssetRegistry::force_register(
+                Origin::root(),
+                asset,
+                sp_runtime::MultiAddress::Id(proposer.clone()),
+                vec![],
+                vec![],
+                12,
+                10,
+            ));
+            // dust of two accounts accumulates in the treasury
+            assert_ok!(Tokens::deposit(asset, &proposer, 100));
+            assert_ok!(Tokens::withdraw(asset, &proposer, 91));
+            assert_ok!(Tokens::deposit(asset, &beneficiary, 100));
+            assert_ok!(Tokens::withdraw(asset, &beneficiary, 95));
+            assert_eq!(
+                Runtime::treasury_balances(),
+                vec![
+                    (AssetId::POLKADEX, AssetBalance::default()),
+                    (asset, AssetBalance { free: 14, reserved: 0, frozen: 0 }),
+                ],
+            );
+
+            Balances::make_free_balance_be(&proposer, 100 * PDEX);
+            assert_ok!(AssetTreasury::propose_spend(
+                Origin::signed(proposer.clone()),
+                asset,
+                10,
+                sp_runtime::MultiAddress::Id(beneficiary.clone()),
+            ));
+            assert_eq!(Balances::reserved_balance(&proposer), 10 * PDEX);
+            assert!(AssetTreasury::approve_proposal(council(1, 2), 0).is_err());
+            assert_ok!(AssetTreasury::approve_proposal(council(3, 5), 0));
+            assert_eq!(Tokens::free_balance(asset, &beneficiary), 10);
+            assert_eq!(Tokens::free_balance(asset, &treasury), 4);
+
+            assert!(AssetTreasury::burn(council(3, 5), asset, 4).is_err());
+            assert_ok!(AssetTreasury::burn(council(2, 3), asset, 4));
+            assert_eq!(Tokens::free_balance(asset, &treasury), 0);
+            assert_eq!(Tokens::total_issuance(asset), 10);
+        });
+    }
+
+    #[test]
+    fn vested_transfers_are_paid_by_the_treasury() {
+        let treasury = TreasuryAccountId::get();
+        let council = |yes, all| Origin::from(pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(yes, all));
+
+        assert_eq!(EnsureRootOrPolkadexTreasury::try_origin(Origin::root()).ok(), Some(treasury.clone()));
+        assert_eq!(EnsureRootOrPolkadexTreasury::try_origin(Origin::signed(treasury.clone())).ok(), Some(treasury.clone()));
+        assert_eq!(EnsureRootOrPolkadexTreasury::try_origin(council(2, 3)).ok(), Some(treasury.clone()));
+        assert!(EnsureRootOrPolkadexTreasury::try_origin(council(3, 5)).is_err());
+        assert!(EnsureRootOrPolkadexTreasury::try_origin(Origin::signed(AccountId::from([1u8; 32]))).is_err());
+        assert!(EnsureRootOrPolkadexTreasury::try_origin(Origin::none()).is_err());
+    }
+
+    #[test]
+    fn approved_treasury_spends_are_paid_as_vesting_schedules() {
+        use frame_support::assert_ok;
+
+        let proposer = AccountId::from([1u8; 32]);
+        let beneficiary = AccountId::from([2u8; 32]);
+        let treasury = TreasuryAccountId::get();
+        let schedule = orml_vesting::VestingSchedule { start: 0, period: 10, period_count: 4, per_period: 100 * PDEX };
+
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            System::set_block_number(1);
+            Balances::make_free_balance_be(&proposer, 100 * PDEX);
+            Balances::make_free_balance_be(&beneficiary, PDEX);
+            Balances::make_free_balance_be(&treasury, 1_000 * PDEX);
+
+            assert_ok!(TreasuryVesting::propose_spend(
+                Origin::signed(proposer.clone()),
+                sp_runtime::MultiAddress::Id(beneficiary.clone()),
+                schedule.clone(),
+            ));
+            assert_eq!(Balances::reserved_balance(&proposer), 20 * PDEX);
+            assert!(TreasuryVesting::approve_proposal(
+                Origin::from(pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(1, 2)),
+                0,
+            ).is_err());
+            assert_ok!(TreasuryVesting::approve_proposal(
+                Origin::from(pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(3, 5)),
+                0,
+            ));
+
+            assert_eq!(Balances::reserved_balance(&proposer), 0);
+            assert_eq!(Balances::free_balance(&treasury), 600 * PDEX);
+            assert_eq!(Balances::free_balance(&beneficiary), 401 * PDEX);
+            assert_eq!(OrmlVesting::vesting_schedules(&beneficiary).into_inner(), vec![schedule]);
+            assert!(Balances::transfer(
+                Origin::signed(beneficiary.clone()),
+                sp_runtime::MultiAddress::Id(proposer.clone()),
+                2 * PDEX,
+            ).is_err());
+        });
+    }
+
+    /// Proposal setting the free balance of `who` to 42 PDEX, with its preimage noted by `who`.
+    fn note_set_balance_proposal(who: &AccountId) -> Hash {
+        use frame_support::assert_ok;
+
+        let proposal = Call::Balances(pallet_balances::Call::set_balance(
+            sp_runtime::MultiAddress::Id(who.clone()),
+            42 * PDEX,
+            0,
+        )).encode();
+        assert_ok!(Democracy::note_preimage(Origin::signed(who.clone()), proposal.clone()));
+        <BlakeTwo256 as traits::Hash>::hash(&proposal)
+    }
+
+    /// Votes aye on `ref_index` with `balance`, ends the referendum at `end` and enacts it `delay`
+    /// blocks later.
+    fn pass_referendum(voter: &AccountId, ref_index: u32, balance: Balance, end: BlockNumber, delay: BlockNumber) {
+        use frame_support::{assert_ok, traits::OnInitialize};
+        use pallet_democracy::{AccountVote, Conviction, Vote};
+
+        let vote = Vote { aye: true, conviction: Conviction::Locked1x };
+        assert_ok!(Democracy::vote(
+            Origin::signed(voter.clone()),
+            ref_index,
+            AccountVote::Standard { vote, balance },
+        ));
+
+        System::set_block_number(end);
+        Democracy::on_initialize(end);
+        assert!(System::events().iter().any(|record| {
+            record.event == Event::Democracy(pallet_democracy::Event::Passed(ref_index))
+        }));
+
+        let enactment = end + delay;
+        System::set_block_number(enactment);
+        Scheduler::on_initialize(enactment);
+    }
+
+    #[test]
+    fn public_referendum_passes_and_is_enacted() {
+        use frame_support::{assert_ok, traits::OnInitialize};
+
+        let proposer = AccountId::from([1u8; 32]);
+        let voter = AccountId::from([2u8; 32]);
+
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            System::set_block_number(1);
+            Balances::make_free_balance_be(&proposer, 1_000 * PDEX);
+            Balances::make_free_balance_be(&voter, 1_000 * PDEX);
+
+            let proposal_hash = note_set_balance_proposal(&proposer);
+            assert_ok!(Democracy::propose(Origin::signed(proposer.clone()), proposal_hash, MinimumDeposit::get()));
+            assert_eq!(Democracy::referendum_count(), 0);
+
+            // the proposal is tabled at the next launch period
+            let launch = LaunchPeriod::get();
+            System::set_block_number(launch);
+            Democracy::on_initialize(launch);
+            assert_eq!(Democracy::referendum_count(), 1);
+
+            pass_referendum(&voter, 0, 500 * PDEX, launch + VotingPeriod::get(), EnactmentPeriod::get());
+            assert_eq!(Balances::free_balance(&proposer), 42 * PDEX);
+        });
+    }
+
+    #[test]
+    fn council_proposal_is_fast_tracked_by_technical_committee() {
+        use frame_support::assert_ok;
+
+        let council = |yes, all| Origin::from(pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(yes, all));
+        let technical = |yes, all| Origin::from(pallet_collective::RawOrigin::<AccountId, TechnicalCollective>::Members(yes, all));
+        let member = AccountId::from([1u8; 32]);
+
+        let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+        sp_io::TestExternalities::new(t).execute_with(|| {
+            System::set_block_number(1);
+            Balances::make_free_balance_be(&member, 1_000 * PDEX);
+
+            let proposal_hash = note_set_balance_proposal(&member);
+            assert!(Democracy::external_propose_majority(council(2, 3), proposal_hash).is_err());
+            assert_ok!(Democracy::external_propose_majority(council(3, 4), proposal_hash));
+            assert!(Democracy::fast_track(technical(1, 2), proposal_hash, FastTrackVotingPeriod::get(), DAY).is_err());
+            assert_ok!(Democracy::fast_track(technical(2, 3), proposal_hash, FastTrackVotingPeriod::get(), DAY));
+            assert_eq!(Democracy::referendum_count(), 1);
+
+            pass_referendum(&member, 0, 500 * PDEX, 1 + FastTrackVotingPeriod::get(), DAY);
+            assert_eq!(Balances::free_balance(&member), 42 * PDEX);
+        });
+    }
+
+    #[test]
+    fn governance_origin_accepts_root_council_and_technical_committee() {
+        let council = |yes, all| Origin::from(pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(yes, all));
+        let technical = |yes, all| Origin::from(pallet_collective::RawOrigin::<AccountId, TechnicalCollective>::Members(yes, all));
+        let governance = Some(GovernanceAccount::get());
+
+        assert_eq!(EnsureGovernance::<_1, _2>::try_origin(Origin::root()).ok(), governance);
+        assert_eq!(EnsureGovernance::<_1, _2>::try_origin(council(1, 2)).ok(), governance);
+        assert_eq!(EnsureGovernance::<_1, _2>::try_origin(council(3, 5)).ok(), governance);
+        assert!(EnsureGovernance::<_1, _2>::try_origin(council(1, 3)).is_err());
+        assert_eq!(EnsureGovernance::<_2, _3>::try_origin(council(2, 3)).ok(), governance);
+        assert!(EnsureGovernance::<_2, _3>::try_origin(council(1, 2)).is_err());
+        assert!(EnsureGovernance::<_1, _2>::try_origin(Origin::from(
+            pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Member(AccountId::from([1u8; 32])),
+        )).is_err());
+
+        assert_eq!(EnsureGovernance::<_1, _2>::try_origin(technical(3, 3)).ok(), governance);
+        assert!(EnsureGovernance::<_1, _2>::try_origin(technical(2, 3)).is_err());
+
+        // the governance account itself has no key and is no governance origin
+        assert!(EnsureGovernance::<_1, _2>::try_origin(Origin::signed(GovernanceAccount::get())).is_err());
+        assert!(EnsureGovernance::<_1, _2>::try_origin(Origin::signed(AccountId::from([1u8; 32]))).is_err());
+        assert!(EnsureGovernance::<_1, _2>::try_origin(Origin::none()).is_err());
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    #[test]
+    fn governance_successful_origin_is_accepted() {
+        assert!(EnsureGovernance::<_1, _2>::try_origin(EnsureGovernance::<_1, _2>::successful_origin()).is_ok());
+        assert!(EnsureGovernance::<_2, _3>::try_origin(EnsureGovernance::<_2, _3>::successful_origin()).is_ok());
+    }
 }
@@ -0,0 +1,871 @@
+// This file is part of Polkadex.
+
+// Copyright (C) 2020-2021 Polkadex oü.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations run by `Executive` on runtime upgrades.
+//!
+//! Every migration has a version and runs once, on the first upgrade while the stored migration
+//! version is below it.
+
+use codec::{Decode, Encode};
+use frame_support::{
+	storage::{
+		migration::{get_storage_value, put_storage_value, storage_key_iter},
+		unhashed,
+	},
+	traits::{
+		BalanceStatus, Currency, ExistenceRequirement, Get, LockIdentifier, LockableCurrency,
+		OnRuntimeUpgrade, ReservableCurrency, WithdrawReasons,
+	},
+	weights::Weight,
+	Blake2_128Concat, PalletId,
+};
+use orml_traits::{MultiCurrency, MultiLockableCurrency, MultiReservableCurrency};
+use orml_vesting::{VestingSchedule, VESTING_LOCK_ID};
+use pallet_balances::Reasons;
+use polkadex_primitives::assets::AssetId;
+use sp_core::hashing::twox_128;
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, Zero},
+	RuntimeDebug, SaturatedConversion,
+};
+use sp_std::{convert::TryInto, vec::Vec};
+
+use crate::{
+	AccountId, AssetVesting, Balance, Balances, BlockNumber, Runtime, System, Tokens,
+	TreasuryAccountId,
+};
+
+/// Prefix of the version of the last migration that was applied.
+const MIGRATIONS_PREFIX: &[u8] = b"PolkadexMigrations";
+
+/// Returns the version of the last migration that was applied.
+pub fn migration_version() -> u16 {
+	get_storage_value::<u16>(MIGRATIONS_PREFIX, b"Version", &[]).unwrap_or_default()
+}
+
+/// Runs `migrate` unless the migration `version` was already applied, and records it.
+fn migrate_once(version: u16, name: &str, migrate: impl FnOnce() -> Weight) -> Weight {
+	let db = <Runtime as frame_system::Config>::DbWeight::get();
+	if migration_version() >= version {
+		log::info!(target: "runtime::migrations", "{} was already applied", name);
+		return db.reads(1)
+	}
+	let weight = migrate();
+	put_storage_value(MIGRATIONS_PREFIX, b"Version", &[], version);
+	weight.saturating_add(db.reads_writes(1, 1))
+}
+
+/// Name `pallet_sudo` was declared with in `construct_runtime!`.
+const PALLET_SUDO_PREFIX: &[u8] = b"Sudo";
+
+/// Identifier the treasury account was derived from before it was merged into the account of
+/// `pallet_treasury`.
+const POLKADEX_TREASURY_ID: PalletId = PalletId(*b"polka/tr");
+
+/// Name `pallet_vesting` was declared with in `construct_runtime!`.
+const PALLET_VESTING_PREFIX: &[u8] = b"Vesting";
+/// Lock identifier of `pallet_vesting`.
+const PALLET_VESTING_ID: LockIdentifier = *b"vesting ";
+
+/// Vesting schedule of `pallet_vesting`, unlocking `per_block` every block after
+/// `starting_block`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub struct VestingInfo {
+	/// Amount locked at `starting_block`
+	pub locked: Balance,
+	/// Amount unlocked every block
+	pub per_block: Balance,
+	/// Block the unlocking starts at
+	pub starting_block: BlockNumber,
+}
+
+impl VestingInfo {
+	/// Amount still locked at block `now`.
+	pub fn locked_at(&self, now: BlockNumber) -> Balance {
+		let vested_blocks = now.saturating_sub(self.starting_block);
+		self.locked
+			.saturating_sub(self.per_block().saturating_mul(vested_blocks.into()))
+	}
+
+	/// Converts the schedule into `orml_vesting` schedules locking the same amount at every
+	/// block: one unlocking `per_block` each block and one unlocking the remainder of `locked`
+	/// in the last block.
+	pub fn into_orml_schedules(self) -> Vec<VestingSchedule<BlockNumber, Balance>> {
+		let per_block = self.per_block();
+		let period_count: u32 = (self.locked / per_block).saturated_into();
+		let remainder = self.locked.saturating_sub(per_block.saturating_mul(period_count.into()));
+
+		let mut schedules = Vec::new();
+		if !period_count.is_zero() {
+			schedules.push(VestingSchedule {
+				start: self.starting_block,
+				period: 1,
+				period_count,
+				per_period: per_block,
+			});
+		}
+		if !remainder.is_zero() {
+			schedules.push(VestingSchedule {
+				start: self.starting_block.saturating_add(period_count),
+				period: 1,
+				period_count: 1,
+				per_period: remainder,
+			});
+		}
+		schedules
+	}
+
+	/// `pallet_vesting` unlocks at least one unit per block.
+	fn per_block(&self) -> Balance {
+		self.per_block.max(1)
+	}
+}
+
+/// Iterates over the schedules `pallet_vesting` left in storage.
+fn pallet_vesting_schedules() -> impl Iterator<Item = (AccountId, Vec<VestingInfo>)> {
+	storage_key_iter::<AccountId, Vec<VestingInfo>, Blake2_128Concat>(
+		PALLET_VESTING_PREFIX,
+		b"Vesting",
+	)
+}
+
+/// Amount locked by the `orml_vesting` schedules of `who` at block `now`.
+fn orml_locked(who: &AccountId, now: BlockNumber) -> Balance {
+	orml_vesting::VestingSchedules::<Runtime>::get(who)
+		.iter()
+		.fold(Zero::zero(), |locked: Balance, schedule| {
+			locked.saturating_add(schedule.locked_amount(now))
+		})
+}
+
+/// Block at which `schedule` has unlocked all of its funds.
+fn schedule_end(schedule: &VestingSchedule<BlockNumber, Balance>) -> BlockNumber {
+	schedule
+		.start
+		.saturating_add(schedule.period.saturating_mul(schedule.period_count))
+}
+
+/// Keeps the first `MaxVestingSchedules - 1` of `schedules` and merges the others into one
+/// schedule locking, until the last of them ends, everything they still lock at block `now`.
+/// The merged schedule never unlocks funds earlier than the schedules it replaces.
+fn merge_excess_schedules(
+	mut schedules: Vec<VestingSchedule<BlockNumber, Balance>>,
+	now: BlockNumber,
+) -> Vec<VestingSchedule<BlockNumber, Balance>> {
+	let max = <Runtime as orml_vesting::Config>::MaxVestingSchedules::get() as usize;
+	if schedules.len() <= max {
+		return schedules
+	}
+	let excess = schedules.split_off(max.saturating_sub(1));
+	let locked = excess.iter().fold(Zero::zero(), |locked: Balance, schedule| {
+		locked.saturating_add(schedule.locked_amount(now))
+	});
+	let end = excess.iter().map(schedule_end).max().unwrap_or(now);
+	if !locked.is_zero() {
+		schedules.push(VestingSchedule {
+			start: now,
+			period: end.saturating_sub(now).max(1),
+			period_count: 1,
+			per_period: locked,
+		});
+	}
+	schedules
+}
+
+/// `orml_vesting` and `pallet_vesting` schedules of an account before the migration.
+#[cfg(feature = "try-runtime")]
+type VestingSnapshot = (AccountId, Vec<VestingSchedule<BlockNumber, Balance>>, Vec<VestingInfo>);
+
+/// Moves the schedules of the removed `pallet_vesting` into `orml_vesting`, replaces its lock
+/// on each vesting account by the `orml_vesting` lock and clears its storage. Schedules beyond
+/// `MaxVestingSchedules` are merged by `merge_excess_schedules`.
+pub struct MigratePalletVestingToOrml;
+
+impl MigratePalletVestingToOrml {
+	/// Version of the migration.
+	pub const VERSION: u16 = 1;
+
+	fn migrate() -> Weight {
+		let now = System::block_number();
+		let mut accounts = 0u64;
+
+		for (who, infos) in pallet_vesting_schedules().drain().collect::<Vec<_>>() {
+			accounts += 1;
+			let schedules: Vec<_> = orml_vesting::VestingSchedules::<Runtime>::get(&who)
+				.into_inner()
+				.into_iter()
+				.chain(
+					infos
+						.into_iter()
+						.flat_map(VestingInfo::into_orml_schedules)
+						.filter(|schedule| !schedule.locked_amount(now).is_zero()),
+				)
+				.collect();
+			let count = schedules.len();
+			let schedules = merge_excess_schedules(schedules, now);
+			if schedules.len() < count {
+				log::warn!(
+					target: "runtime::migrations",
+					"Merged {} vesting schedules of {:?}: too many schedules",
+					count - schedules.len() + 1,
+					who,
+				);
+			}
+			match schedules.try_into() {
+				Ok(schedules) => orml_vesting::VestingSchedules::<Runtime>::insert(&who, schedules),
+				Err(_) => log::error!(
+					target: "runtime::migrations",
+					"Failed to store the vesting schedules of {:?}",
+					who,
+				),
+			}
+
+			Balances::remove_lock(PALLET_VESTING_ID, &who);
+			let locked = orml_locked(&who, now);
+			if locked.is_zero() {
+				Balances::remove_lock(VESTING_LOCK_ID, &who);
+			} else {
+				Balances::set_lock(VESTING_LOCK_ID, &who, locked, WithdrawReasons::all());
+			}
+		}
+		let _ = unhashed::kill_prefix(&twox_128(PALLET_VESTING_PREFIX), None);
+
+		log::info!(target: "runtime::migrations", "Migrated {} pallet_vesting accounts", accounts);
+		<Runtime as frame_system::Config>::DbWeight::get()
+			.reads_writes(3 * accounts + 1, 4 * accounts + 1)
+	}
+}
+
+impl OnRuntimeUpgrade for MigratePalletVestingToOrml {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_once(Self::VERSION, "MigratePalletVestingToOrml", Self::migrate)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+		let pending = migration_version() < Self::VERSION;
+		Self::set_temp_storage(pending, "vesting_pending");
+		if !pending {
+			return Ok(())
+		}
+		let expected: Vec<VestingSnapshot> = pallet_vesting_schedules()
+			.map(|(who, infos)| {
+				let schedules = orml_vesting::VestingSchedules::<Runtime>::get(&who).into_inner();
+				(who, schedules, infos)
+			})
+			.collect();
+		log::info!(
+			target: "runtime::migrations",
+			"{} pallet_vesting accounts to migrate",
+			expected.len(),
+		);
+		Self::set_temp_storage(expected, "vesting_schedules");
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		use frame_support::{ensure, traits::OnRuntimeUpgradeHelpersExt};
+
+		if Self::get_temp_storage::<bool>("vesting_pending") != Some(true) {
+			return Ok(())
+		}
+		ensure!(migration_version() >= Self::VERSION, "Migration version was not recorded");
+		let now = System::block_number();
+		let expected: Vec<VestingSnapshot> = Self::get_temp_storage("vesting_schedules")
+			.ok_or("Vesting schedules were not recorded")?;
+		ensure!(pallet_vesting_schedules().next().is_none(), "pallet_vesting schedules are left");
+		for (who, schedules, infos) in expected {
+			let locked_at = |at: BlockNumber| {
+				let locked = schedules.iter().fold(Zero::zero(), |locked: Balance, schedule| {
+					locked.saturating_add(schedule.locked_amount(at))
+				});
+				infos
+					.iter()
+					.fold(locked, |locked, info| locked.saturating_add(info.locked_at(at)))
+			};
+			let locked = locked_at(now);
+			ensure!(orml_locked(&who, now) == locked, "Vested amount changed");
+			// funds must not unlock earlier than before, checked at every block a schedule ends
+			let converted = infos.iter().cloned().flat_map(VestingInfo::into_orml_schedules);
+			let ends: Vec<BlockNumber> =
+				schedules.iter().cloned().chain(converted).map(|s| schedule_end(&s)).collect();
+			for at in ends.into_iter().filter(|at| *at > now) {
+				ensure!(orml_locked(&who, at) >= locked_at(at), "Vested funds unlock earlier");
+				let before = at.saturating_sub(1);
+				ensure!(
+					orml_locked(&who, before) >= locked_at(before),
+					"Vested funds unlock earlier"
+				);
+			}
+			let locks = Balances::locks(&who);
+			ensure!(
+				locks.iter().all(|lock| lock.id != PALLET_VESTING_ID),
+				"pallet_vesting lock is left"
+			);
+			ensure!(
+				locked.is_zero() ||
+					locks.iter().any(|lock| lock.id == VESTING_LOCK_ID && lock.amount == locked),
+				"orml_vesting lock does not match the vested amount"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Clears the storage of the removed `pallet_sudo`, leaving Root to referenda and the
+/// council and technical committee origins.
+pub struct RemoveSudo;
+
+impl RemoveSudo {
+	/// Version of the migration.
+	pub const VERSION: u16 = 2;
+
+	fn migrate() -> Weight {
+		if let Some(key) = get_storage_value::<AccountId>(PALLET_SUDO_PREFIX, b"Key", &[]) {
+			log::info!(target: "runtime::migrations", "Removed sudo key {:?}", key);
+		}
+		let _ = unhashed::kill_prefix(&twox_128(PALLET_SUDO_PREFIX), None);
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+	}
+}
+
+impl OnRuntimeUpgrade for RemoveSudo {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_once(Self::VERSION, "RemoveSudo", Self::migrate)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		use crate::{Council, TechnicalCommittee};
+		use frame_support::{ensure, traits::OnRuntimeUpgradeHelpersExt};
+
+		let pending = migration_version() < Self::VERSION;
+		Self::set_temp_storage(pending, "sudo_pending");
+		if !pending {
+			return Ok(())
+		}
+		// governance must be able to act once the sudo key is gone
+		ensure!(!Council::members().is_empty(), "No council members");
+		ensure!(!TechnicalCommittee::members().is_empty(), "No technical committee members");
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		use frame_support::{
+			ensure, storage::migration::have_storage_value, traits::OnRuntimeUpgradeHelpersExt,
+		};
+
+		if Self::get_temp_storage::<bool>("sudo_pending") != Some(true) {
+			return Ok(())
+		}
+		ensure!(migration_version() >= Self::VERSION, "Migration version was not recorded");
+		ensure!(!have_storage_value(PALLET_SUDO_PREFIX, b"Key", &[]), "Sudo key is left");
+		Ok(())
+	}
+}
+
+/// Account the treasury was derived from before it was merged into the account of
+/// `pallet_treasury`.
+fn polkadex_treasury_account() -> AccountId {
+	POLKADEX_TREASURY_ID.into_account()
+}
+
+/// Withdraw reasons a native lock applies to.
+fn withdraw_reasons(reasons: Reasons) -> WithdrawReasons {
+	match reasons {
+		Reasons::Fee => WithdrawReasons::TRANSACTION_PAYMENT,
+		Reasons::Misc => WithdrawReasons::except(WithdrawReasons::TRANSACTION_PAYMENT),
+		Reasons::All => WithdrawReasons::all(),
+	}
+}
+
+/// Total balance of `who` in `asset_id`.
+fn total_balance(who: &AccountId, asset_id: AssetId) -> Balance {
+	match asset_id {
+		AssetId::POLKADEX => Balances::total_balance(who),
+		AssetId::Asset(_) => Tokens::total_balance(asset_id, who),
+	}
+}
+
+/// Moves everything the former `polka/tr` treasury account holds into the account of
+/// `pallet_treasury`, so that `pallet_treasury` can spend it. Reserved balances are repatriated
+/// as free balance, `orml_vesting` and `AssetVesting` schedules are re-keyed to the new account
+/// and the vesting and other locks are re-created there, so that locked funds stay locked.
+pub struct UnifyTreasuryAccounts;
+
+impl UnifyTreasuryAccounts {
+	/// Version of the migration.
+	pub const VERSION: u16 = 3;
+
+	/// Moves the native balance, locks and `orml_vesting` schedules of `old` to `new`.
+	fn sweep_native(old: &AccountId, new: &AccountId) {
+		let now = System::block_number();
+		let reserved = Balances::reserved_balance(old);
+		if let Err(e) = Balances::repatriate_reserved(old, new, reserved, BalanceStatus::Free) {
+			log::error!(
+				target: "runtime::migrations",
+				"Failed to repatriate the reserved balance of the treasury: {:?}",
+				e,
+			);
+		}
+
+		let locks = Balances::locks(old);
+		for lock in locks.iter() {
+			Balances::remove_lock(lock.id, old);
+		}
+		let free = Balances::free_balance(old);
+		if let Err(e) =
+			<Balances as Currency<_>>::transfer(old, new, free, ExistenceRequirement::AllowDeath)
+		{
+			log::error!(target: "runtime::migrations", "Failed to sweep the treasury: {:?}", e);
+		}
+
+		let schedules: Vec<_> = orml_vesting::VestingSchedules::<Runtime>::get(new)
+			.into_inner()
+			.into_iter()
+			.chain(orml_vesting::VestingSchedules::<Runtime>::take(old).into_inner())
+			.collect();
+		if !schedules.is_empty() {
+			match merge_excess_schedules(schedules, now).try_into() {
+				Ok(schedules) => orml_vesting::VestingSchedules::<Runtime>::insert(new, schedules),
+				Err(_) => log::error!(
+					target: "runtime::migrations",
+					"Failed to store the vesting schedules of the treasury",
+				),
+			}
+		}
+		let vesting = orml_locked(new, now);
+		if !vesting.is_zero() {
+			Balances::set_lock(VESTING_LOCK_ID, new, vesting, WithdrawReasons::all());
+		}
+		for lock in locks.into_iter().filter(|lock| lock.id != VESTING_LOCK_ID) {
+			Balances::extend_lock(lock.id, new, lock.amount, withdraw_reasons(lock.reasons));
+		}
+	}
+
+	/// Moves the balance, locks and `AssetVesting` schedules of `old` in `asset_id` to `new`.
+	fn sweep_asset(old: &AccountId, new: &AccountId, asset_id: AssetId) {
+		let reserved = Tokens::reserved_balance(asset_id, old);
+		if let Err(e) = <Tokens as MultiReservableCurrency<_>>::repatriate_reserved(
+			asset_id,
+			old,
+			new,
+			reserved,
+			BalanceStatus::Free,
+		) {
+			log::error!(
+				target: "runtime::migrations",
+				"Failed to repatriate the reserved {:?} of the treasury: {:?}",
+				asset_id,
+				e,
+			);
+		}
+
+		let locks = orml_tokens::Locks::<Runtime>::get(old, asset_id);
+		for lock in locks.iter() {
+			let _ = <Tokens as MultiLockableCurrency<_>>::remove_lock(lock.id, asset_id, old);
+		}
+		let free = Tokens::free_balance(asset_id, old);
+		if let Err(e) = <Tokens as MultiCurrency<_>>::transfer(asset_id, old, new, free) {
+			log::error!(
+				target: "runtime::migrations",
+				"Failed to sweep {:?} of the treasury: {:?}",
+				asset_id,
+				e,
+			);
+		}
+
+		let schedules = asset_vesting_pallet::VestingSchedules::<Runtime>::take(old, asset_id);
+		if !schedules.is_empty() {
+			asset_vesting_pallet::VestingSchedules::<Runtime>::mutate(new, asset_id, |merged| {
+				merged.extend(schedules)
+			});
+		}
+		let vesting = AssetVesting::locked_balance(new, asset_id);
+		if !vesting.is_zero() {
+			let _ = <Tokens as MultiLockableCurrency<_>>::set_lock(
+				asset_vesting_pallet::ASSET_VESTING_ID,
+				asset_id,
+				new,
+				vesting,
+			);
+		}
+		for lock in locks
+			.into_iter()
+			.filter(|lock| lock.id != asset_vesting_pallet::ASSET_VESTING_ID)
+		{
+			let _ = <Tokens as MultiLockableCurrency<_>>::extend_lock(
+				lock.id,
+				asset_id,
+				new,
+				lock.amount,
+			);
+		}
+	}
+
+	fn migrate() -> Weight {
+		let old = polkadex_treasury_account();
+		let new = TreasuryAccountId::get();
+
+		let assets: Vec<AssetId> = orml_tokens::Accounts::<Runtime>::iter_prefix(&old)
+			.map(|(asset_id, _)| asset_id)
+			.collect();
+		for asset_id in &assets {
+			Self::sweep_asset(&old, &new, *asset_id);
+		}
+		// last, as the asset accounts may keep the former account alive
+		Self::sweep_native(&old, &new);
+
+		let left = crate::account_balances(&old)
+			.into_iter()
+			.filter(|(_, balance)| !balance.free.is_zero() || !balance.reserved.is_zero())
+			.collect::<Vec<_>>();
+		if !left.is_empty() {
+			log::error!(
+				target: "runtime::migrations",
+				"Balances left on the former treasury account: {:?}",
+				left,
+			);
+		}
+		log::info!(
+			target: "runtime::migrations",
+			"Swept native PDEX and {} assets into the treasury",
+			assets.len(),
+		);
+		let assets = assets.len() as u64;
+		<Runtime as frame_system::Config>::DbWeight::get()
+			.reads_writes(8 * assets + 10, 10 * assets + 10)
+	}
+}
+
+impl OnRuntimeUpgrade for UnifyTreasuryAccounts {
+	fn on_runtime_upgrade() -> Weight {
+		migrate_once(Self::VERSION, "UnifyTreasuryAccounts", Self::migrate)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+		let pending = migration_version() < Self::VERSION;
+		Self::set_temp_storage(pending, "treasury_pending");
+		if !pending {
+			return Ok(())
+		}
+		let old = polkadex_treasury_account();
+		let new = TreasuryAccountId::get();
+		let now = System::block_number();
+		let expected: Vec<(AssetId, Balance)> = crate::account_balances(&old)
+			.into_iter()
+			.map(|(asset_id, _)| {
+				(
+					asset_id,
+					total_balance(&new, asset_id).saturating_add(total_balance(&old, asset_id)),
+				)
+			})
+			.collect();
+		Self::set_temp_storage(expected, "treasury_balances");
+		let vesting = orml_locked(&old, now).saturating_add(orml_locked(&new, now));
+		Self::set_temp_storage(vesting, "treasury_vesting");
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		use frame_support::{ensure, traits::OnRuntimeUpgradeHelpersExt};
+
+		if Self::get_temp_storage::<bool>("treasury_pending") != Some(true) {
+			return Ok(())
+		}
+		ensure!(migration_version() >= Self::VERSION, "Migration version was not recorded");
+		let expected: Vec<(AssetId, Balance)> = Self::get_temp_storage("treasury_balances")
+			.ok_or("Treasury balances were not recorded")?;
+		let vesting: Balance = Self::get_temp_storage("treasury_vesting")
+			.ok_or("Treasury vesting was not recorded")?;
+		let old = polkadex_treasury_account();
+		let new = TreasuryAccountId::get();
+
+		// nothing is left on the former account
+		ensure!(
+			crate::account_balances(&old)
+				.iter()
+				.all(|(_, balance)| balance.free.is_zero() && balance.reserved.is_zero()),
+			"Funds are left on the former treasury account"
+		);
+		ensure!(Balances::locks(&old).is_empty(), "Locks are left on the former treasury account");
+		ensure!(
+			orml_vesting::VestingSchedules::<Runtime>::get(&old).is_empty(),
+			"Vesting schedules are left on the former treasury account"
+		);
+		ensure!(
+			asset_vesting_pallet::VestingSchedules::<Runtime>::iter_prefix(&old)
+				.next()
+				.is_none(),
+			"Asset vesting schedules are left on the former treasury account"
+		);
+
+		for (asset_id, total) in expected {
+			ensure!(total_balance(&new, asset_id) == total, "Treasury balance changed");
+		}
+		let now = System::block_number();
+		ensure!(orml_locked(&new, now) == vesting, "Treasury vesting changed");
+		ensure!(
+			vesting.is_zero() ||
+				Balances::locks(&new)
+					.iter()
+					.any(|lock| lock.id == VESTING_LOCK_ID && lock.amount == vesting),
+			"Treasury vesting lock does not match the vested amount"
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::{
+		storage::migration::{have_storage_value, put_storage_value},
+		StorageHasher,
+	};
+
+	use super::*;
+	use crate::{constants::currency::PDEX, Origin, SpendPeriod, Treasury};
+	use frame_support::{assert_ok, traits::OnInitialize};
+
+	#[test]
+	fn converted_schedules_lock_like_pallet_vesting() {
+		let infos = [
+			VestingInfo { locked: 1005, per_block: 10, starting_block: 5 },
+			VestingInfo { locked: 1000, per_block: 10, starting_block: 5 },
+			VestingInfo { locked: 7, per_block: 10, starting_block: 5 },
+			VestingInfo { locked: 10, per_block: 0, starting_block: 5 },
+		];
+		for info in &infos {
+			let schedules = info.clone().into_orml_schedules();
+			for now in 0..200 {
+				let locked = schedules
+					.iter()
+					.fold(0, |locked, schedule| locked + schedule.locked_amount(now));
+				assert_eq!(locked, info.locked_at(now), "{:?} at block {}", info, now);
+			}
+		}
+	}
+
+	#[test]
+	fn pallet_vesting_schedules_move_to_orml_vesting() {
+		let who = AccountId::from([1u8; 32]);
+		let vesting = VestingInfo { locked: 1005, per_block: 10, starting_block: 5 };
+		let vested = VestingInfo { locked: 10, per_block: 10, starting_block: 0 };
+
+		let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+		sp_io::TestExternalities::new(t).execute_with(|| {
+			System::set_block_number(10);
+			Balances::make_free_balance_be(&who, PDEX);
+			Balances::set_lock(PALLET_VESTING_ID, &who, 955, WithdrawReasons::all());
+			put_storage_value(
+				PALLET_VESTING_PREFIX,
+				b"Vesting",
+				&Blake2_128Concat::hash(&who.encode()),
+				vec![vesting.clone(), vested],
+			);
+			put_storage_value(PALLET_VESTING_PREFIX, b"StorageVersion", &[], 1u8);
+
+			MigratePalletVestingToOrml::on_runtime_upgrade();
+
+			assert!(pallet_vesting_schedules().next().is_none());
+			assert!(unhashed::get_raw(&frame_support::storage::storage_prefix(
+				PALLET_VESTING_PREFIX,
+				b"StorageVersion"
+			))
+			.is_none());
+			assert_eq!(
+				orml_vesting::VestingSchedules::<Runtime>::get(&who).into_inner(),
+				vesting.clone().into_orml_schedules(),
+			);
+			let locks = Balances::locks(&who);
+			assert_eq!(locks.len(), 1);
+			assert_eq!((locks[0].id, locks[0].amount), (VESTING_LOCK_ID, 955));
+			for now in 10..200 {
+				assert_eq!(orml_locked(&who, now), vesting.locked_at(now));
+			}
+
+			// the migration runs once
+			assert_eq!(migration_version(), MigratePalletVestingToOrml::VERSION);
+			Balances::set_lock(PALLET_VESTING_ID, &who, 955, WithdrawReasons::all());
+			MigratePalletVestingToOrml::on_runtime_upgrade();
+			assert_eq!(Balances::locks(&who).len(), 2);
+		});
+	}
+
+	#[test]
+	fn excess_vesting_schedules_are_merged() {
+		let who = AccountId::from([1u8; 32]);
+		let max = <Runtime as orml_vesting::Config>::MaxVestingSchedules::get();
+		let infos: Vec<VestingInfo> = (0..max + 5)
+			.map(|i| VestingInfo { locked: 100, per_block: 1, starting_block: i })
+			.collect();
+		let locked_at = |now| infos.iter().fold(0, |locked, info| locked + info.locked_at(now));
+
+		let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+		sp_io::TestExternalities::new(t).execute_with(|| {
+			System::set_block_number(10);
+			Balances::make_free_balance_be(&who, 1_000 * PDEX);
+			put_storage_value(
+				PALLET_VESTING_PREFIX,
+				b"Vesting",
+				&Blake2_128Concat::hash(&who.encode()),
+				infos.clone(),
+			);
+
+			MigratePalletVestingToOrml::on_runtime_upgrade();
+
+			assert_eq!(orml_vesting::VestingSchedules::<Runtime>::get(&who).len(), max as usize);
+			assert_eq!(orml_locked(&who, 10), locked_at(10));
+			assert_eq!(Balances::locks(&who)[0].amount, locked_at(10));
+			// no vested funds unlock earlier than with `pallet_vesting`
+			for now in 10..max + 110 {
+				assert!(orml_locked(&who, now) >= locked_at(now), "at block {}", now);
+			}
+			assert_eq!(orml_locked(&who, max + 104), 0);
+		});
+	}
+
+	#[test]
+	fn former_treasury_account_is_swept() {
+		let old = polkadex_treasury_account();
+		let new = TreasuryAccountId::get();
+		let asset = AssetId::Asset(1);
+		let schedule = VestingSchedule { start: 0, period: 10, period_count: 4, per_period: PDEX };
+
+		let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+		sp_io::TestExternalities::new(t).execute_with(|| {
+			System::set_block_number(10);
+			Balances::make_free_balance_be(&old, 10 * PDEX);
+			Balances::make_free_balance_be(&new, PDEX);
+			assert_ok!(Balances::reserve(&old, PDEX));
+			orml_vesting::VestingSchedules::<Runtime>::mutate(&old, |schedules| {
+				assert!(schedules.try_push(schedule.clone()).is_ok())
+			});
+			Balances::set_lock(VESTING_LOCK_ID, &old, 3 * PDEX, WithdrawReasons::all());
+			Balances::set_lock(*b"stakingl", &old, 2 * PDEX, WithdrawReasons::all());
+			assert_ok!(Tokens::deposit(asset, &old, 5 * PDEX));
+			assert_ok!(Tokens::reserve(asset, &old, PDEX));
+			asset_vesting_pallet::VestingSchedules::<Runtime>::insert(
+				&old,
+				asset,
+				vec![schedule.clone()],
+			);
+			assert_ok!(<Tokens as MultiLockableCurrency<_>>::set_lock(
+				asset_vesting_pallet::ASSET_VESTING_ID,
+				asset,
+				&old,
+				3 * PDEX,
+			));
+
+			UnifyTreasuryAccounts::on_runtime_upgrade();
+			assert_eq!(migration_version(), UnifyTreasuryAccounts::VERSION);
+
+			// nothing is left on the former account
+			assert_eq!(Balances::total_balance(&old), 0);
+			assert!(Balances::locks(&old).is_empty());
+			assert!(orml_vesting::VestingSchedules::<Runtime>::get(&old).is_empty());
+			assert_eq!(Tokens::total_balance(asset, &old), 0);
+			assert!(orml_tokens::Locks::<Runtime>::get(&old, asset).is_empty());
+			assert!(asset_vesting_pallet::VestingSchedules::<Runtime>::get(&old, asset).is_empty());
+
+			// funds, including the reserved ones, move with their locks and schedules
+			assert_eq!(Balances::free_balance(&new), 11 * PDEX);
+			assert_eq!(Balances::reserved_balance(&new), 0);
+			assert_eq!(
+				orml_vesting::VestingSchedules::<Runtime>::get(&new).into_inner(),
+				vec![schedule.clone()]
+			);
+			let locks = Balances::locks(&new);
+			assert_eq!(locks.len(), 2);
+			assert!(locks.iter().any(|lock| lock.id == VESTING_LOCK_ID && lock.amount == 3 * PDEX));
+			assert!(locks.iter().any(|lock| lock.id == *b"stakingl" && lock.amount == 2 * PDEX));
+			assert_eq!(Tokens::free_balance(asset, &new), 5 * PDEX);
+			assert_eq!(Tokens::accounts(&new, asset).frozen, 3 * PDEX);
+			assert_eq!(
+				asset_vesting_pallet::VestingSchedules::<Runtime>::get(&new, asset),
+				vec![schedule]
+			);
+
+			// the migration runs once
+			Balances::make_free_balance_be(&old, 10 * PDEX);
+			UnifyTreasuryAccounts::on_runtime_upgrade();
+			assert_eq!(Balances::free_balance(&new), 11 * PDEX);
+		});
+	}
+
+	#[test]
+	fn treasury_spends_merged_funds() {
+		let old = polkadex_treasury_account();
+		let new = TreasuryAccountId::get();
+		let proposer = AccountId::from([1u8; 32]);
+		let beneficiary = AccountId::from([2u8; 32]);
+
+		let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+		sp_io::TestExternalities::new(t).execute_with(|| {
+			System::set_block_number(1);
+			Balances::make_free_balance_be(&old, 100 * PDEX);
+			Balances::make_free_balance_be(&proposer, 10 * PDEX);
+
+			UnifyTreasuryAccounts::on_runtime_upgrade();
+			assert_eq!(Balances::free_balance(&old), 0);
+			assert_eq!(Treasury::pot(), 99 * PDEX);
+
+			assert_ok!(Treasury::propose_spend(
+				Origin::signed(proposer.clone()),
+				10 * PDEX,
+				sp_runtime::MultiAddress::Id(beneficiary.clone()),
+			));
+			assert_ok!(Treasury::approve_proposal(Origin::root(), 0));
+			<Treasury as OnInitialize<BlockNumber>>::on_initialize(SpendPeriod::get());
+
+			assert_eq!(Balances::free_balance(&beneficiary), 10 * PDEX);
+			assert_eq!(Balances::free_balance(&proposer), 10 * PDEX);
+			// half of the unspent pot is burnt
+			assert_eq!(Balances::free_balance(&new), 90 * PDEX - 89 * PDEX / 2);
+		});
+	}
+
+	#[test]
+	fn sudo_key_is_removed() {
+		let key = AccountId::from([1u8; 32]);
+
+		let t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+		sp_io::TestExternalities::new(t).execute_with(|| {
+			put_storage_value(PALLET_SUDO_PREFIX, b"Key", &[], key.clone());
+			assert!(have_storage_value(PALLET_SUDO_PREFIX, b"Key", &[]));
+
+			RemoveSudo::on_runtime_upgrade();
+
+			assert!(!have_storage_value(PALLET_SUDO_PREFIX, b"Key", &[]));
+			assert_eq!(migration_version(), RemoveSudo::VERSION);
+
+			// the migration runs once
+			put_storage_value(PALLET_SUDO_PREFIX, b"Key", &[], key);
+			RemoveSudo::on_runtime_upgrade();
+			assert!(have_storage_value(PALLET_SUDO_PREFIX, b"Key", &[]));
+		});
+	}
+}
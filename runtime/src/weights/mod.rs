@@ -15,5 +15,7 @@
 
 //! A list of the different weight modules for our runtime.
 
+pub mod orml_currencies;
+pub mod orml_tokens;
 pub mod pallet_session;
 pub mod pallet_utility;
@@ -0,0 +1,59 @@
+#!/usr/bin/env bash
+# Regenerates the weight files of the given pallets (all of them if none are given) with the
+# benchmark CLI. Run it on the reference validator hardware and commit the output unchanged.
+set -e
+
+cd "$(dirname "$0")/.."
+
+# Pallets of this repository get a `WeightInfo` trait from the weight template, the weights of
+# upstream pallets implement the trait of their pallet.
+declare -A OUTPUTS=(
+	[erc20_pdex_migration_pallet]=./pallets/pdex-migration/src/weights.rs
+	[orml_currencies]=./runtime/src/weights/orml_currencies.rs
+	[orml_tokens]=./runtime/src/weights/orml_tokens.rs
+	[asset_fee_payment_pallet]=./pallets/asset-fee-payment/src/weights.rs
+	[asset_registry_pallet]=./pallets/asset-registry/src/weights.rs
+	[asset_vesting_pallet]=./pallets/asset-vesting/src/weights.rs
+	[treasury_vesting_pallet]=./pallets/treasury-vesting/src/weights.rs
+	[asset_treasury_pallet]=./pallets/asset-treasury/src/weights.rs
+)
+declare -A TEMPLATES=(
+	[erc20_pdex_migration_pallet]=./.maintain/frame-weight-template.hbs
+	[asset_fee_payment_pallet]=./.maintain/frame-weight-template.hbs
+	[asset_registry_pallet]=./.maintain/frame-weight-template.hbs
+	[asset_vesting_pallet]=./.maintain/frame-weight-template.hbs
+	[treasury_vesting_pallet]=./.maintain/frame-weight-template.hbs
+	[asset_treasury_pallet]=./.maintain/frame-weight-template.hbs
+)
+
+cargo build --release -p polkadex-node --features runtime-benchmarks
+
+PALLETS=("$@")
+if [ ${#PALLETS[@]} -eq 0 ]; then
+	PALLETS=("${!OUTPUTS[@]}")
+fi
+
+for PALLET in "${PALLETS[@]}"; do
+	OUTPUT=${OUTPUTS[$PALLET]}
+	if [ -z "$OUTPUT" ]; then
+		echo "*** Unknown pallet $PALLET"
+		exit 1
+	fi
+	TEMPLATE=()
+	if [ -n "${TEMPLATES[$PALLET]}" ]; then
+		TEMPLATE=(--template="${TEMPLATES[$PALLET]}")
+	fi
+	echo "*** Benchmarking $PALLET into $OUTPUT"
+	./target/release/polkadex-node benchmark \
+		--chain=dev \
+		--steps=50 \
+		--repeat=20 \
+		--pallet="$PALLET" \
+		--extrinsic='*' \
+		--execution=wasm \
+		--wasm-execution=compiled \
+		--heap-pages=4096 \
+		--header=./.maintain/HEADER \
+		--output="$OUTPUT" \
+		"${TEMPLATE[@]}"
+done